@@ -0,0 +1,36 @@
+//! Compile check for using `optics` from a `#![no_std]` crate without `alloc`.
+//!
+//! This crate is never published. Building it on its own, with
+//! `cargo build -p optics-no-std-check`, compiles `optics` without its default features, which
+//! ensures the core optic traits and their composition do not depend on `std` or `alloc`.
+#![no_std]
+
+use optics::{HasSetter, HasTotalGetter, mapped_lens};
+
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+pub struct Player {
+    pub position: Position,
+    pub lives: u8,
+}
+
+/// Composes two lenses and writes through the composition, without allocating.
+#[must_use]
+pub fn advance(mut player: Player, dx: i32) -> Player {
+    let position = mapped_lens(
+        |p: &Player| Position {
+            x: p.position.x,
+            y: p.position.y,
+        },
+        |p: &mut Player, position| p.position = position,
+    );
+    let x = mapped_lens(|p: &Position| p.x, |p: &mut Position, x| p.x = x);
+    let player_x = position.compose_with_lens(x);
+
+    let moved = player_x.get(&player) + dx;
+    player_x.set(&mut player, moved);
+    player
+}
@@ -0,0 +1,450 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::fmt::{Display, Write};
+#[cfg(feature = "std")]
+use core::hash::Hash;
+use core::iter::{self, Product, Sum};
+use core::ops::ControlFlow;
+#[cfg(feature = "std")]
+use std::collections::HashMap;
+
+/// A base trait for optics that can read zero or more focus values.
+///
+/// This trait defines the ability to visit every value of type `A` the optic focuses on inside a
+/// source of type `S`, in a well-defined order. It serves as the foundational read operation of
+/// multi-focus optics like traversals.
+///
+/// Implementors only need to provide [`HasFold::try_fold`], all other operations are derived
+/// from it.
+///
+/// # Notes
+///
+/// - Currently, you will likely need to Clone or Copy the focused values in order to extract
+///   them from the source.
+///
+/// # Implementors
+///
+///   - [`Fold`] — read-only optic for multiple focus values.
+///   - [`Traversal`] — optic that allows reading and modifying multiple focus values.
+///
+/// [`Fold`]: crate::Fold
+/// [`Traversal`]: crate::Traversal
+pub trait HasFold<S, A> {
+    /// Folds every value the optic focuses on into an accumulator, allowing early termination.
+    ///
+    /// The focus values are visited in order, calling `f` with the current accumulator and the
+    /// value. If `f` returns [`ControlFlow::Break`], the fold stops immediately without visiting
+    /// the remaining values.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `init`: The initial value of the accumulator.
+    /// - `f`: The folding function.
+    ///
+    /// # Returns
+    ///
+    /// `ControlFlow::Continue` with the final accumulator if every value was visited, or
+    /// `ControlFlow::Break` with the accumulator returned by `f` when it stopped the fold.
+    fn try_fold<B, F>(&self, source: &S, init: B, f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>;
+
+    /// Folds every value the optic focuses on into an accumulator.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `init`: The initial value of the accumulator.
+    /// - `f`: The folding function.
+    ///
+    /// # Returns
+    ///
+    /// The final value of the accumulator.
+    fn fold<B, F>(&self, source: &S, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, A) -> B,
+    {
+        match self.try_fold(source, init, |acc, a| ControlFlow::Continue(f(acc, a))) {
+            ControlFlow::Continue(acc) | ControlFlow::Break(acc) => acc,
+        }
+    }
+
+    /// Combines the values the optic focuses on, in order, without an initial accumulator.
+    ///
+    /// The first value is the starting accumulator, and every following value is combined into it
+    /// with `f`. This suits combinations that have no natural identity value to start [`fold`]
+    /// from, such as taking a minimum by some custom order.
+    ///
+    /// [`fold`]: HasFold::fold
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `f`: The combining function, called with the accumulator and the next value.
+    ///
+    /// # Returns
+    ///
+    /// The combined value, or `None` if the optic focuses on no values in `source`.
+    fn reduce<F>(&self, source: &S, mut f: F) -> Option<A>
+    where
+        F: FnMut(A, A) -> A,
+    {
+        self.fold(source, None, |acc, a| match acc {
+            Some(acc) => Some(f(acc, a)),
+            None => Some(a),
+        })
+    }
+
+    /// Returns the minimum of the values the optic focuses on.
+    ///
+    /// If several values are equally minimal, the first one is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    ///
+    /// # Returns
+    ///
+    /// The minimal focus value, or `None` if the optic focuses on no values in `source`.
+    fn min(&self, source: &S) -> Option<A>
+    where
+        A: Ord,
+    {
+        self.fold(source, None, |acc, a| match acc {
+            Some(m) if m <= a => Some(m),
+            _ => Some(a),
+        })
+    }
+
+    /// Returns the maximum of the values the optic focuses on.
+    ///
+    /// If several values are equally maximal, the last one is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    ///
+    /// # Returns
+    ///
+    /// The maximal focus value, or `None` if the optic focuses on no values in `source`.
+    fn max(&self, source: &S) -> Option<A>
+    where
+        A: Ord,
+    {
+        self.fold(source, None, |acc, a| match acc {
+            Some(m) if m > a => Some(m),
+            _ => Some(a),
+        })
+    }
+
+    /// Returns the focus value that gives the minimum value from the specified function.
+    ///
+    /// If several values are equally minimal, the first one is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `f`: The function computing the key each value is compared by.
+    ///
+    /// # Returns
+    ///
+    /// The focus value with the minimal key, or `None` if the optic focuses on no values in
+    /// `source`.
+    fn min_by_key<K, F>(&self, source: &S, mut f: F) -> Option<A>
+    where
+        K: Ord,
+        F: FnMut(&A) -> K,
+    {
+        self.fold(source, None, |acc, a| {
+            let key = f(&a);
+            match acc {
+                Some((m, mk)) if mk <= key => Some((m, mk)),
+                _ => Some((a, key)),
+            }
+        })
+        .map(|(m, _)| m)
+    }
+
+    /// Returns the focus value that gives the maximum value from the specified function.
+    ///
+    /// If several values are equally maximal, the last one is returned.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `f`: The function computing the key each value is compared by.
+    ///
+    /// # Returns
+    ///
+    /// The focus value with the maximal key, or `None` if the optic focuses on no values in
+    /// `source`.
+    fn max_by_key<K, F>(&self, source: &S, mut f: F) -> Option<A>
+    where
+        K: Ord,
+        F: FnMut(&A) -> K,
+    {
+        self.fold(source, None, |acc, a| {
+            let key = f(&a);
+            match acc {
+                Some((m, mk)) if mk > key => Some((m, mk)),
+                _ => Some((a, key)),
+            }
+        })
+        .map(|(m, _)| m)
+    }
+
+    /// Counts the values the optic focuses on.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    fn count(&self, source: &S) -> usize {
+        self.fold(source, 0, |n, _| n + 1)
+    }
+
+    /// Sums the values the optic focuses on.
+    ///
+    /// No intermediate collection is built: the values are added one at a time to a running
+    /// total, starting from the empty sum.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    ///
+    /// # Returns
+    ///
+    /// The sum of the focus values, or the empty sum (e.g. `0`) if the optic focuses on no values
+    /// in `source`.
+    fn sum(&self, source: &S) -> A
+    where
+        A: Sum,
+    {
+        self.fold(source, iter::empty().sum(), |acc, a| {
+            [acc, a].into_iter().sum()
+        })
+    }
+
+    /// Multiplies the values the optic focuses on.
+    ///
+    /// No intermediate collection is built: the values are multiplied one at a time into a
+    /// running product, starting from the empty product.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    ///
+    /// # Returns
+    ///
+    /// The product of the focus values, or the empty product (e.g. `1`) if the optic focuses on
+    /// no values in `source`.
+    fn product(&self, source: &S) -> A
+    where
+        A: Product,
+    {
+        self.fold(source, iter::empty().product(), |acc, a| {
+            [acc, a].into_iter().product()
+        })
+    }
+
+    /// Tests whether any value the optic focuses on satisfies a predicate.
+    ///
+    /// Stops at the first value for which `predicate` returns `true`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `predicate`: The predicate each value is tested with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `predicate` holds for at least one value, `false` otherwise (including when the
+    /// optic focuses on no values).
+    fn any<F>(&self, source: &S, mut predicate: F) -> bool
+    where
+        F: FnMut(A) -> bool,
+    {
+        self.try_fold(source, false, |_, a| {
+            if predicate(a) {
+                ControlFlow::Break(true)
+            } else {
+                ControlFlow::Continue(false)
+            }
+        })
+        .is_break()
+    }
+
+    /// Tests whether every value the optic focuses on satisfies a predicate.
+    ///
+    /// Stops at the first value for which `predicate` returns `false`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `predicate`: The predicate each value is tested with.
+    ///
+    /// # Returns
+    ///
+    /// `true` if `predicate` holds for every value (including when the optic focuses on no
+    /// values), `false` otherwise.
+    fn all<F>(&self, source: &S, mut predicate: F) -> bool
+    where
+        F: FnMut(A) -> bool,
+    {
+        self.try_fold(source, true, |_, a| {
+            if predicate(a) {
+                ControlFlow::Continue(true)
+            } else {
+                ControlFlow::Break(false)
+            }
+        })
+        .is_continue()
+    }
+
+    /// Returns the first value the optic focuses on that satisfies a predicate.
+    ///
+    /// Stops at the first value for which `predicate` returns `true`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `predicate`: The predicate each value is tested with.
+    ///
+    /// # Returns
+    ///
+    /// The first matching value, or `None` if no value satisfies `predicate`.
+    fn find<F>(&self, source: &S, mut predicate: F) -> Option<A>
+    where
+        F: FnMut(&A) -> bool,
+    {
+        match self.try_fold(source, None, |_, a| {
+            if predicate(&a) {
+                ControlFlow::Break(Some(a))
+            } else {
+                ControlFlow::Continue(None)
+            }
+        }) {
+            ControlFlow::Continue(found) | ControlFlow::Break(found) => found,
+        }
+    }
+
+    /// Collects every value the optic focuses on into a `Vec`, in traversal order.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    #[cfg(feature = "alloc")]
+    fn get_all(&self, source: &S) -> Vec<A> {
+        self.fold(source, Vec::new(), |mut acc, a| {
+            acc.push(a);
+            acc
+        })
+    }
+
+    /// Concatenates the values the optic focuses on into a `String`, in traversal order, with
+    /// `sep` between consecutive values.
+    ///
+    /// Each value is formatted with its [`Display`] implementation.
+    ///
+    /// [`Display`]: core::fmt::Display
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `sep`: The separator inserted between consecutive values.
+    ///
+    /// # Returns
+    ///
+    /// The joined string, which is empty if the optic focuses on no values in `source`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, each};
+    ///
+    /// assert_eq!(each().join(&vec![1, 2, 3], "-"), "1-2-3");
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn join(&self, source: &S, sep: &str) -> String
+    where
+        A: Display,
+    {
+        let mut first = true;
+        self.fold(source, String::new(), |mut acc, a| {
+            if !first {
+                acc.push_str(sep);
+            }
+            first = false;
+            let _ = write!(acc, "{a}");
+            acc
+        })
+    }
+
+    /// Collects the running accumulations of folding every value the optic focuses on.
+    ///
+    /// The `i`-th element of the result is the accumulator after visiting the first `i + 1`
+    /// values, so the result has one element per focus value and `init` itself is not part of it.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `init`: The initial value of the accumulator.
+    /// - `f`: The function computing the next accumulator from the current one and a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, each};
+    ///
+    /// let values = vec![1, 2, 3, 4];
+    /// assert_eq!(each().scan(&values, 0, |sum, x| sum + x), vec![1, 3, 6, 10]);
+    /// ```
+    #[cfg(feature = "alloc")]
+    fn scan<B, F>(&self, source: &S, init: B, mut f: F) -> Vec<B>
+    where
+        B: Clone,
+        F: FnMut(&B, A) -> B,
+    {
+        let mut acc = init;
+        self.fold(source, Vec::new(), |mut accs, a| {
+            acc = f(&acc, a);
+            accs.push(acc.clone());
+            accs
+        })
+    }
+
+    /// Groups every value the optic focuses on into buckets by a key derived from the value.
+    ///
+    /// Each bucket holds the values with the same key, in traversal order.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the values are read from.
+    /// - `key`: The function deriving the key of a value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, each};
+    ///
+    /// let words = vec!["apple", "avocado", "banana"];
+    /// let by_initial = each().group_by(&words, |w: &&str| w.chars().next());
+    ///
+    /// assert_eq!(by_initial[&Some('a')], ["apple", "avocado"]);
+    /// assert_eq!(by_initial[&Some('b')], ["banana"]);
+    /// ```
+    #[cfg(feature = "std")]
+    fn group_by<K, F>(&self, source: &S, key: F) -> HashMap<K, Vec<A>>
+    where
+        K: Eq + Hash,
+        F: Fn(&A) -> K,
+    {
+        self.fold(source, HashMap::new(), |mut groups, a| {
+            groups.entry(key(&a)).or_insert_with(Vec::new).push(a);
+            groups
+        })
+    }
+}
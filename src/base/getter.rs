@@ -7,7 +7,7 @@
 /// # Associated Types
 ///
 /// - `GetterError`: The type of the error that may occur during retrieval. This will propagete
-/// as the error type of retrieval of concrete optics that implement this trait.
+///   as the error type of retrieval of concrete optics that implement this trait.
 ///
 /// # Notes
 /// - Currently, you will likely need to Clone or Copy the result in order to extract it from the source.
@@ -37,5 +37,9 @@ pub trait HasGetter<S, A> {
     /// # Returns
     ///
     /// Returns a `Result<A, Self::GetterError>`, of the value the optic focuses on.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::GetterError` if the focus cannot be retrieved from the source.
     fn try_get(&self, source: &S) -> Result<A, Self::GetterError>;
 }
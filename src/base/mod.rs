@@ -1,7 +1,13 @@
+mod fold;
 mod getter;
+mod modify_all;
+mod ref_getter;
 mod reversible;
 mod setter;
 
+pub use fold::HasFold;
 pub use getter::HasGetter;
+pub use modify_all::{HasFilterMapAll, HasModifyAll};
+pub use ref_getter::HasRefGetter;
 pub use reversible::HasReverseGet;
 pub use setter::HasSetter;
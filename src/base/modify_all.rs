@@ -0,0 +1,40 @@
+/// A base trait for optics that can modify zero or more focus values in place.
+///
+/// This trait defines the ability to apply a transformation to every value of type `A` the optic
+/// focuses on inside a mutable source of type `S`. It serves as the foundational write operation
+/// of multi-focus optics like traversals.
+///
+/// # Implementors
+///
+///   - [`Traversal`] — optic that allows reading and modifying multiple focus values.
+///
+/// [`Traversal`]: crate::Traversal
+pub trait HasModifyAll<S, A> {
+    /// Replaces every value the optic focuses on with the result of applying `f` to it.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` to be modified.
+    /// - `f`: The transformation applied to each focus value, in traversal order.
+    fn modify_all<F>(&self, source: &mut S, f: F)
+    where
+        F: FnMut(A) -> A;
+}
+
+/// A base trait for optics that can modify or remove zero or more focus values in place.
+///
+/// This is a generalization of [`HasModifyAll`] for optics focusing on the elements of a
+/// collection, where the transformation may also decide to drop an element from the collection
+/// entirely, much like `Vec::retain` combined with a mapping.
+pub trait HasFilterMapAll<S, A> {
+    /// Replaces every value the optic focuses on with the result of applying `f` to it, removing
+    /// the values for which `f` returns `None`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` to be modified.
+    /// - `f`: The transformation applied to each focus value, in traversal order.
+    fn filter_map_all<F>(&self, source: &mut S, f: F)
+    where
+        F: FnMut(A) -> Option<A>;
+}
@@ -0,0 +1,44 @@
+/// A base trait for optics that can borrow their focus value from the source.
+///
+/// This trait defines the ability to obtain a shared or mutable reference to a value of type `A`
+/// stored inside a source of type `S`, potentially failing with an error of type
+/// `RefGetterError`. Unlike [`HasGetter`], it does not require cloning the focus value, and
+/// writes through the mutable reference happen in place.
+///
+/// This is only possible for optics whose focus value is actually stored in the source, so it is
+/// an optional capability, on top of the optic's [`HasGetter`].
+///
+/// # Associated Types
+///
+/// - `RefGetterError`: The type of the error that may occur when the focus is not present in the
+///   source.
+///
+/// [`HasGetter`]: crate::HasGetter
+pub trait HasRefGetter<S, A> {
+    /// The type of error that may occur during borrowing. Use `Infallible` for infallible optics.
+    type RefGetterError;
+
+    /// Attempts to borrow the value of type `A` the optic focuses on in a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the value is to be borrowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::RefGetterError` if the focus is not present in the source.
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError>;
+
+    /// Attempts to mutably borrow the value of type `A` the optic focuses on in a source of type
+    /// `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A mutable reference to the source of type `S` from which the value is to be
+    ///   borrowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::RefGetterError` if the focus is not present in the source.
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError>;
+}
@@ -1,3 +1,6 @@
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+
 /// A base trait for optics that provides a partial reversible operation.
 ///
 /// This trait defines the ability to reverse a value of type `A` back into a source of type `S`,
@@ -7,16 +10,16 @@
 /// # Associated Types
 ///
 /// - `ReverseError`: The type of the error that may occur during the reverse operation. This will propagete
-/// as the error type of reverse retrieval of concrete optics that implement this trait.
+///   as the error type of reverse retrieval of concrete optics that implement this trait.
 ///
 /// # Notes
 ///
 /// - Currently, you will likely need to clone or copy the value in order to reverse it into the source.
 /// - Logically a `PartialReversible<S, A>` implies `PartialGetter<A, S>`, but I have not yet found a way
-/// around the compiler trait cohesion limitations
+///   around the compiler trait cohesion limitations
 /// - One way could be to remove `PartialReversible` entirely, and use `PartialGetter<A, S>` instead of
-/// `PartialReversible<S, A>`, but that comes with its own set of ergonomics issues, like how to
-/// disambuguate between the two `try_get` operations without too much boilerplate.
+///   `PartialReversible<S, A>`, but that comes with its own set of ergonomics issues, like how to
+///   disambuguate between the two `try_get` operations without too much boilerplate.
 ///
 /// # Implementors
 ///
@@ -39,5 +42,29 @@ pub trait HasReverseGet<S, A> {
     /// # Returns
     ///
     /// Returns a `Result<S, Self::ReverseError>`, of the value the optic focuses on.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Self::ReverseError` if the value cannot be reversed into a source.
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError>;
+
+    /// Attempts to reverse every value of a slice back into a source, in order.
+    ///
+    /// Each value is reversed independently with [`HasReverseGet::try_reverse_get`], so a failure
+    /// for one value does not prevent the others from being reversed.
+    ///
+    /// # Parameters
+    ///
+    /// - `values`: The values of type `A` to be reversed into sources.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec` with the result of reversing each value, in the order of `values`.
+    #[cfg(feature = "alloc")]
+    fn try_reverse_get_many(&self, values: &[A]) -> Vec<Result<S, Self::ReverseError>> {
+        values
+            .iter()
+            .map(|value| self.try_reverse_get(value))
+            .collect()
+    }
 }
@@ -22,4 +22,24 @@ pub trait HasSetter<S, A> {
     /// - `source`: A mutable reference to the source of type `S` into which the value is to be set.
     /// - `value`: The value of type `A` to be set into the source.
     fn set(&self, source: &mut S, value: A);
+
+    /// Sets the same value of type `A` the optic focuses on in every source of a slice.
+    ///
+    /// This is useful to broadcast an update of a field across a collection of records. To
+    /// transform the current focus values instead, see [`HasOver::modify_all_sources`].
+    ///
+    /// # Parameters
+    ///
+    /// - `sources`: A mutable slice of sources of type `S` into which the value is to be set.
+    /// - `value`: The value of type `A` to be set into each of the sources.
+    ///
+    /// [`HasOver::modify_all_sources`]: crate::HasOver::modify_all_sources
+    fn set_all(&self, sources: &mut [S], value: A)
+    where
+        A: Clone,
+    {
+        for source in sources {
+            self.set(source, value.clone());
+        }
+    }
 }
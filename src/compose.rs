@@ -0,0 +1,486 @@
+// Every impl below returns a more specific type than the trait declares, so that callers keep the
+// full capabilities of the composed optic instead of an opaque `impl Sized`.
+#![allow(refining_impl_trait)]
+
+use crate::{
+    FallibleIso, FallibleIsoImpl, Getter, GetterImpl, Iso, IsoImpl, Lens, LensImpl, PartialGetter,
+    PartialGetterImpl, Prism, PrismImpl, Traversal, TraversalImpl,
+};
+use core::convert::identity;
+
+/// Composition of two optic wrappers, picking the most capable optic type supported by both.
+///
+/// This trait mirrors the `compose_with_*` methods on the `*Impl` wrappers, but dispatches on the
+/// type of the second optic, so the same call works regardless of the kinds of optics involved.
+/// The resulting optic type follows the composition table in the crate root documentation,
+/// e.g. a `Lens` composed with a `Prism` results in a `Prism`.
+///
+/// # Capability lattice
+///
+/// Optic kinds are ordered by the capabilities they provide, each kind being weaker than the
+/// kinds connected above it:
+///
+/// ```text
+///              Iso
+///             /    \
+///          Lens    FallibleIso
+///         /    \   /
+///    Getter    Prism
+///         \    /
+///      PartialGetter
+/// ```
+///
+/// Composing two optics results in the strongest kind that is weaker than or equal to both of
+/// them. That choice only depends on the two kinds and not on their order, so `a.compose(b)` and
+/// `b.compose(a)` (for suitably typed `a` and `b`) always result in the same kind of optic.
+/// `Traversal` sits below both `Lens` and `Prism` in this ordering, and only composes with the
+/// optics that can also set.
+///
+/// It can be called either in method position, `a.compose(b)`, or as a free function,
+/// `Compose::compose(a, b)`, which is handy in macros or generic helper code.
+///
+/// # Error types
+///
+/// When both optics can fail, their errors must be of the same type, which becomes the error type
+/// of the composed optic. To compose optics with different error types use the corresponding
+/// `compose_with_*` or `compose_with_*_with_mappers` methods.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{Compose, HasSetter, HasTotalGetter, mapped_lens};
+///
+/// struct Inner { value: u32 }
+/// struct Outer { inner: Inner }
+///
+/// let inner_lens = mapped_lens(
+///     |o: &Outer| Inner { value: o.inner.value },
+///     |o, i| o.inner = i,
+/// );
+/// let value_lens = mapped_lens(|i: &Inner| i.value, |i, v| i.value = v);
+///
+/// let composed = Compose::compose(inner_lens, value_lens);
+///
+/// let mut outer = Outer { inner: Inner { value: 1 } };
+/// composed.set(&mut outer, 2);
+/// assert_eq!(composed.get(&outer), 2);
+/// ```
+pub trait Compose<Rhs> {
+    /// Composes `self` with `other`, focusing through `self` first and then `other`.
+    fn compose(self, other: Rhs) -> impl Sized;
+}
+
+impl<S, I, A, L1: Lens<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>> for LensImpl<S, I, L1> {
+    fn compose(self, other: LensImpl<I, A, L2>) -> LensImpl<S, A, impl Lens<S, A>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for LensImpl<S, I, L1>
+{
+    fn compose(self, other: GetterImpl<I, A, G2>) -> GetterImpl<S, A, impl Getter<S, A>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, PG2: PartialGetter<I, A>> Compose<PartialGetterImpl<I, A, PG2>>
+    for LensImpl<S, I, L1>
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        self.compose_with_partial_getter(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, P2: Prism<I, A>> Compose<PrismImpl<I, A, P2>> for LensImpl<S, I, L1> {
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P2::GetterError>> {
+        self.compose_with_prism(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, FI2: FallibleIso<I, A>> Compose<FallibleIsoImpl<I, A, FI2>>
+    for LensImpl<S, I, L1>
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = FI2::GetterError>> {
+        self.compose_with_fallible_iso(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>> for LensImpl<S, I, L1> {
+    fn compose(self, other: IsoImpl<I, A, ISO2>) -> LensImpl<S, A, impl Lens<S, A>> {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, P2: Prism<I, A, GetterError = P1::GetterError>>
+    Compose<PrismImpl<I, A, P2>> for PrismImpl<S, I, P1>
+{
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_prism_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, P1, PG2> Compose<PartialGetterImpl<I, A, PG2>> for PrismImpl<S, I, P1>
+where
+    P1: Prism<S, I>,
+    PG2: PartialGetter<I, A, GetterError = P1::GetterError>,
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_partial_getter_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for PrismImpl<S, I, P1>
+{
+    fn compose(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>> for PrismImpl<S, I, P1> {
+    fn compose(
+        self,
+        other: LensImpl<I, A, L2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, FI2: FallibleIso<I, A, GetterError = P1::GetterError>>
+    Compose<FallibleIsoImpl<I, A, FI2>> for PrismImpl<S, I, P1>
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_fallible_iso_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>>
+    for PrismImpl<S, I, P1>
+{
+    fn compose(
+        self,
+        other: IsoImpl<I, A, ISO2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>> for IsoImpl<S, I, ISO1> {
+    fn compose(self, other: LensImpl<I, A, L2>) -> LensImpl<S, A, impl Lens<S, A>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(self, other: GetterImpl<I, A, G2>) -> GetterImpl<S, A, impl Getter<S, A>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, PG2: PartialGetter<I, A>> Compose<PartialGetterImpl<I, A, PG2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        self.compose_with_partial_getter(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, P2: Prism<I, A>> Compose<PrismImpl<I, A, P2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P2::GetterError>> {
+        self.compose_with_prism(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, FI2: FallibleIso<I, A>> Compose<FallibleIsoImpl<I, A, FI2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<S, A, GetterError = FI2::GetterError, ReverseError = FI2::ReverseError>,
+    > {
+        self.compose_with_fallible_iso(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(self, other: IsoImpl<I, A, ISO2>) -> IsoImpl<S, A, impl Iso<S, A>> {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, FI1: FallibleIso<S, I>, P2: Prism<I, A, GetterError = FI1::GetterError>>
+    Compose<PrismImpl<I, A, P2>> for FallibleIsoImpl<S, I, FI1>
+{
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = FI1::GetterError>> {
+        self.compose_with_prism_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, FI1: FallibleIso<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for FallibleIsoImpl<S, I, FI1>
+{
+    fn compose(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI1::GetterError>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, FI1, PG2> Compose<PartialGetterImpl<I, A, PG2>> for FallibleIsoImpl<S, I, FI1>
+where
+    FI1: FallibleIso<S, I>,
+    PG2: PartialGetter<I, A, GetterError = FI1::GetterError>,
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI1::GetterError>> {
+        self.compose_with_partial_getter_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, FI1: FallibleIso<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>>
+    for FallibleIsoImpl<S, I, FI1>
+{
+    fn compose(
+        self,
+        other: LensImpl<I, A, L2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = FI1::GetterError>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, FI1, FI2> Compose<FallibleIsoImpl<I, A, FI2>> for FallibleIsoImpl<S, I, FI1>
+where
+    FI1: FallibleIso<S, I>,
+    FI2: FallibleIso<I, A, GetterError = FI1::GetterError, ReverseError = FI1::ReverseError>,
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<S, A, GetterError = FI1::GetterError, ReverseError = FI1::ReverseError>,
+    > {
+        self.compose_with_fallible_iso_with_mappers(other, identity, identity, identity, identity)
+    }
+}
+
+impl<S, I, A, FI1: FallibleIso<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>>
+    for FallibleIsoImpl<S, I, FI1>
+{
+    fn compose(
+        self,
+        other: IsoImpl<I, A, ISO2>,
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<S, A, GetterError = FI1::GetterError, ReverseError = FI1::ReverseError>,
+    > {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(self, other: GetterImpl<I, A, G2>) -> GetterImpl<S, A, impl Getter<S, A>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, PG2: PartialGetter<I, A>> Compose<PartialGetterImpl<I, A, PG2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        self.compose_with_partial_getter(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, P2: Prism<I, A>> Compose<PrismImpl<I, A, P2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P2::GetterError>> {
+        self.compose_with_prism(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(self, other: LensImpl<I, A, L2>) -> GetterImpl<S, A, impl Getter<S, A>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, FI2: FallibleIso<I, A>> Compose<FallibleIsoImpl<I, A, FI2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI2::GetterError>> {
+        self.compose_with_fallible_iso(other)
+    }
+}
+
+impl<S, I, A, G1: Getter<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>>
+    for GetterImpl<S, I, G1>
+{
+    fn compose(self, other: IsoImpl<I, A, ISO2>) -> GetterImpl<S, A, impl Getter<S, A>> {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, PG1, PG2> Compose<PartialGetterImpl<I, A, PG2>> for PartialGetterImpl<S, I, PG1>
+where
+    PG1: PartialGetter<S, I>,
+    PG2: PartialGetter<I, A, GetterError = PG1::GetterError>,
+{
+    fn compose(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_partial_getter_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, PG1: PartialGetter<S, I>, G2: Getter<I, A>> Compose<GetterImpl<I, A, G2>>
+    for PartialGetterImpl<S, I, PG1>
+{
+    fn compose(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_getter(other)
+    }
+}
+
+impl<S, I, A, PG1, P2> Compose<PrismImpl<I, A, P2>> for PartialGetterImpl<S, I, PG1>
+where
+    PG1: PartialGetter<S, I>,
+    P2: Prism<I, A, GetterError = PG1::GetterError>,
+{
+    fn compose(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_prism_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, PG1: PartialGetter<S, I>, L2: Lens<I, A>> Compose<LensImpl<I, A, L2>>
+    for PartialGetterImpl<S, I, PG1>
+{
+    fn compose(
+        self,
+        other: LensImpl<I, A, L2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_lens(other)
+    }
+}
+
+impl<S, I, A, PG1, FI2> Compose<FallibleIsoImpl<I, A, FI2>> for PartialGetterImpl<S, I, PG1>
+where
+    PG1: PartialGetter<S, I>,
+    FI2: FallibleIso<I, A, GetterError = PG1::GetterError>,
+{
+    fn compose(
+        self,
+        other: FallibleIsoImpl<I, A, FI2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_fallible_iso_with_mappers(other, identity, identity)
+    }
+}
+
+impl<S, I, A, PG1: PartialGetter<S, I>, ISO2: Iso<I, A>> Compose<IsoImpl<I, A, ISO2>>
+    for PartialGetterImpl<S, I, PG1>
+{
+    fn compose(
+        self,
+        other: IsoImpl<I, A, ISO2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
+        self.compose_with_iso(other)
+    }
+}
+
+impl<S, I, A, L1: Lens<S, I>, T2: Traversal<I, A>> Compose<TraversalImpl<I, A, T2>>
+    for LensImpl<S, I, L1>
+{
+    fn compose(self, other: TraversalImpl<I, A, T2>) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        self.compose_with_traversal(other)
+    }
+}
+
+impl<S, I, A, P1: Prism<S, I>, T2: Traversal<I, A>> Compose<TraversalImpl<I, A, T2>>
+    for PrismImpl<S, I, P1>
+{
+    fn compose(self, other: TraversalImpl<I, A, T2>) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        self.compose_with_traversal(other)
+    }
+}
+
+impl<S, I, A, ISO1: Iso<S, I>, T2: Traversal<I, A>> Compose<TraversalImpl<I, A, T2>>
+    for IsoImpl<S, I, ISO1>
+{
+    fn compose(self, other: TraversalImpl<I, A, T2>) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        self.compose_with_traversal(other)
+    }
+}
+
+impl<S, I, A, T1: Traversal<S, I>, T2: Traversal<I, A>> Compose<TraversalImpl<I, A, T2>>
+    for TraversalImpl<S, I, T1>
+{
+    fn compose(self, other: TraversalImpl<I, A, T2>) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        self.compose_with_traversal(other)
+    }
+}
@@ -0,0 +1,64 @@
+use crate::{HasGetter, HasSetter};
+
+/// Provides a way to check whether writing through an optic would reach its focus, without
+/// performing the write.
+///
+/// This trait is automatically implemented for any optic that implements both [`HasGetter`] and
+/// [`HasSetter`]. The focus is reachable exactly when [`HasGetter::try_get`] succeeds, so for
+/// optics with an infallible getter, like lenses and isos, `can_set` is always `true`, and for
+/// prisms and fallible isos it depends on the source.
+///
+/// Note that the check performs a read, so it costs as much as a `try_get`, including any clone
+/// of the focus value.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasCanSet, mapped_prism};
+///
+/// enum Field {
+///     Editable(String),
+///     Locked,
+/// }
+///
+/// let editable = mapped_prism(
+///     |f: &Field| match f {
+///         Field::Editable(s) => Ok(s.clone()),
+///         Field::Locked => Err(()),
+///     },
+///     |f, s| {
+///         if let Field::Editable(old) = f {
+///             *old = s;
+///         }
+///     },
+/// );
+///
+/// assert!(editable.can_set(&Field::Editable("text".to_string())));
+/// assert!(!editable.can_set(&Field::Locked));
+/// ```
+///
+/// # See also:
+///
+/// [`HasGetter`]: base trait for optics that provides a potentially fallible getter operation.
+/// [`HasSetter`]: base trait for optics that provides a setter operation.
+pub trait HasCanSet<S, A> {
+    /// Checks whether a `set` on a source of type `S` would reach the focus value.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` the write is checked against.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the optic currently focuses on a value in `source`, `false` otherwise.
+    fn can_set(&self, source: &S) -> bool;
+}
+
+impl<S, A, T> HasCanSet<S, A> for T
+where
+    T: HasGetter<S, A> + HasSetter<S, A>,
+{
+    fn can_set(&self, source: &S) -> bool {
+        self.try_get(source).is_ok()
+    }
+}
@@ -1,7 +1,11 @@
+mod can_set;
+mod over;
 mod total_getter;
+mod total_ref_getter;
 mod total_reverse_get;
-mod over;
 
+pub use can_set::HasCanSet;
+pub use over::HasOver;
 pub use total_getter::HasTotalGetter;
+pub use total_ref_getter::HasTotalRefGetter;
 pub use total_reverse_get::HasTotalReverseGet;
-pub use over::HasOver;
\ No newline at end of file
@@ -32,25 +32,47 @@ use crate::{HasGetter, HasSetter};
 /// [`Infallible`]: std::convert::Infallible
 /// [`HasSetter`]: crate::HasSetter
 pub trait HasOver<S, A> {
-  /// Retrieves a value of type `A` from a source of type `S`.
-  ///
-  /// # Parameters
-  ///
-  /// - `source`: A reference to the source of type `S` from which the value is to be retrieved.
-  ///
-  /// # Returns
-  ///
-  /// Returns the value of type `A` that the optic focuses on.
-  fn over<F>(&self, source: &mut S, f: F) where F: Fn(A) -> A;
+    /// Retrieves a value of type `A` from a source of type `S`.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: A reference to the source of type `S` from which the value is to be retrieved.
+    ///
+    /// # Returns
+    ///
+    /// Returns the value of type `A` that the optic focuses on.
+    fn over<F>(&self, source: &mut S, f: F)
+    where
+        F: Fn(A) -> A;
+
+    /// Applies a transformation function over the target value of every source of a slice.
+    ///
+    /// Sources the optic fails to focus on are left unchanged, like with [`HasOver::over`].
+    ///
+    /// # Parameters
+    ///
+    /// - `sources`: A mutable slice of sources of type `S` to be transformed.
+    /// - `f`: The transformation applied to the focus value of each source.
+    fn modify_all_sources<F>(&self, sources: &mut [S], f: F)
+    where
+        F: Fn(A) -> A,
+    {
+        for source in sources {
+            self.over(source, &f);
+        }
+    }
 }
 
 impl<S, A, T> HasOver<S, A> for T
 where
-  T: HasGetter<S, A> + HasSetter<S, A>,
+    T: HasGetter<S, A> + HasSetter<S, A>,
 {
-  fn over<F>(&self, source: &mut S, f: F) where F: Fn(A) -> A {
-    if let Ok(value) = self.try_get(source) {
-      self.set(source, f(value))
+    fn over<F>(&self, source: &mut S, f: F)
+    where
+        F: Fn(A) -> A,
+    {
+        if let Ok(value) = self.try_get(source) {
+            self.set(source, f(value));
+        }
     }
-  }
 }
@@ -0,0 +1,45 @@
+use crate::HasRefGetter;
+use core::convert::Infallible;
+
+/// Provides a simplified interface for optics with infallible borrowing operations.
+///
+/// This trait is automatically implemented for any optic that implements
+/// [`HasRefGetter`] with a [`RefGetterError`] type of [`Infallible`].
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{HasTotalRefGetter, box_deref_lens};
+///
+/// let mut boxed = Box::new(vec![1, 2]);
+///
+/// box_deref_lens().get_mut(&mut boxed).push(3);
+/// assert_eq!(box_deref_lens().get_ref(&boxed), &vec![1, 2, 3]);
+/// ```
+///
+/// [`RefGetterError`]: crate::HasRefGetter::RefGetterError
+/// [`Infallible`]: core::convert::Infallible
+pub trait HasTotalRefGetter<S, A> {
+    /// Borrows the value of type `A` the optic focuses on in a source of type `S`.
+    fn get_ref<'a>(&self, source: &'a S) -> &'a A;
+
+    /// Mutably borrows the value of type `A` the optic focuses on in a source of type `S`.
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut A;
+}
+
+impl<S, A, T> HasTotalRefGetter<S, A> for T
+where
+    T: HasRefGetter<S, A, RefGetterError = Infallible>,
+{
+    fn get_ref<'a>(&self, source: &'a S) -> &'a A {
+        match self.try_get_ref(source) {
+            Ok(value) => value,
+        }
+    }
+
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut A {
+        match self.try_get_mut(source) {
+            Ok(value) => value,
+        }
+    }
+}
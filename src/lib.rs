@@ -1,12 +1,13 @@
 #![doc = include_str!("../README.md")]
 
-// #![cfg_attr(not(test), no_std)]
+#![cfg_attr(not(feature = "std"), no_std)]
 // #![deny(missing_docs)]
 #![warn(clippy::all)]
 #![warn(clippy::pedantic)]
 #![allow(clippy::module_name_repetitions)]
 #![forbid(unsafe_code)]
 
+#[cfg(feature = "alloc")]
 extern crate alloc;
 
 use core::convert::Infallible;
@@ -16,24 +17,86 @@ fn infallible<E>(e: Infallible) -> E {
 }
 
 mod base;
+mod compose;
 mod extensions;
 mod optics;
+mod try_optic;
 
-#[cfg(test)]
+#[cfg(all(test, feature = "std"))]
 mod test;
 
-pub use base::{HasGetter, HasReverseGet, HasSetter};
-pub use extensions::{HasOver, HasTotalGetter, HasTotalReverseGet};
+pub use base::{
+  HasFilterMapAll, HasFold, HasGetter, HasModifyAll, HasRefGetter, HasReverseGet, HasSetter,
+};
+pub use compose::Compose;
+pub use try_optic::CompositeError;
+pub use extensions::{HasCanSet, HasOver, HasTotalGetter, HasTotalRefGetter, HasTotalReverseGet};
 
 pub use optics::fallible_iso::{
-  composed_fallible_iso, identity_fallible_iso, mapped_fallible_iso, FallibleIso, FallibleIsoImpl,
+  composed_fallible_iso, identity_fallible_iso, mapped_fallible_iso, try_into_iso, FallibleIso,
+  FallibleIsoImpl, OutOfRange,
+};
+#[cfg(feature = "alloc")]
+pub use optics::fallible_iso::{
+  env_var, hex_iso, non_empty_trimmed_iso, non_empty_trimmed_prism, parse_iso, permute_iso,
+  str_enum_prism, BlankError, EnvVarError, HexError, InvalidPermutation, StrEnumError,
+};
+#[cfg(feature = "std")]
+pub use optics::fallible_iso::{ratio_iso, system_time_iso, RatioError, UnixTimeError};
+pub use optics::fold::{composed_fold, identity_fold, mapped_fold, Fold, FoldImpl};
+#[cfg(feature = "alloc")]
+pub use optics::fold::{lines_fold, windows_fold, words_fold};
+pub use optics::getter::{
+  compare_by, composed_getter, deref_lift_getter, discriminant_getter, identity_getter,
+  mapped_getter, Getter, GetterImpl,
+};
+#[cfg(feature = "alloc")]
+pub use optics::getter::{string_byte_len_getter, string_char_count_getter};
+pub use optics::iso::{
+  celsius_fahrenheit_iso, composed_iso, identity_iso, mapped_iso, meters_feet_iso,
+  option_default_iso, radians_degrees_iso, rotate_iso, transpose_iso, Iso, IsoImpl,
 };
-pub use optics::getter::{composed_getter, identity_getter, mapped_getter, Getter, GetterImpl};
-pub use optics::iso::{composed_iso, identity_iso, mapped_iso, Iso, IsoImpl};
-pub use optics::lens::{composed_lens, identity_lens, mapped_lens, Lens, LensImpl};
+#[cfg(feature = "alloc")]
+pub use optics::iso::unzip_iso;
+pub use optics::lens::{
+  bit_lens, cell_lens, clamped_lens, composed_lens, deref_lift, field_lens, identity_lens,
+  mapped_lens, ok_lens, pair_split_lens, read_write, result_ok_lens, tuple_0, tuple_1, tuple_lens,
+  Lens, LensImpl, TupleLens,
+};
+#[cfg(feature = "alloc")]
+pub use optics::lens::{
+  box_deref_lens, dedup_adjacent_lens, deque_back_lens, deque_front_lens, sorted_view_lens, split_at_lens,
+  split_at_string_lens, vec_len_lens,
+};
+#[cfg(feature = "std")]
+pub use optics::lens::arc_mutex_lens;
 pub use optics::partial_getter::{
-  composed_partial_getter, identity_partial_getter, mapped_partial_getter, PartialGetter,
-  PartialGetterImpl,
+  composed_partial_getter, err_into_getter, identity_partial_getter, mapped_partial_getter,
+  PartialGetter, PartialGetterImpl,
 };
-pub use optics::prism::{composed_prism, identity_prism, mapped_prism, Prism, PrismImpl};
+#[cfg(feature = "alloc")]
+pub use optics::partial_getter::find_getter;
+#[cfg(feature = "std")]
+pub use optics::partial_getter::{weak_upgrade_getter, DroppedError};
+pub use optics::prism::{
+  composed_prism, field_prism, identity_prism, mapped_prism, nonnull_prism, ok_prism,
+  poll_ready_prism, prism_opt, some_prism, variant_prism, PathError, PendingError, Prism,
+  PrismImpl,
+};
+#[cfg(feature = "alloc")]
+pub use optics::prism::{leading_int_prism, substr_prism, vec_index_clamped, SubstrError};
+#[cfg(feature = "either")]
+pub use optics::prism::{left_prism, right_prism};
+#[cfg(feature = "std")]
+pub use optics::prism::map_entry_eq;
 pub use optics::setter::{composed_setter, identity_setter, mapped_setter, Setter, SetterImpl};
+#[cfg(feature = "alloc")]
+pub use optics::setter::sorted_vec_insert;
+#[cfg(feature = "std")]
+pub use optics::setter::{map_merge, map_merge_with};
+pub use optics::traversal::{
+  array_each, composed_traversal, identity_traversal, mapped_traversal, option_each, Traversal,
+  TraversalImpl,
+};
+#[cfg(feature = "alloc")]
+pub use optics::traversal::{each, each_removable, flatten_traversal, lines_traversal};
@@ -0,0 +1,110 @@
+use crate::{FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+use core::str::FromStr;
+
+/// The error returned by [`env_var`] when a variable cannot be read.
+///
+/// [`env_var`]: crate::env_var
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum EnvVarError<E> {
+    /// The map has no entry for the key.
+    Missing(String),
+    /// The entry exists, but its value could not be parsed.
+    Parse(E),
+}
+
+impl<E: fmt::Display> fmt::Display for EnvVarError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EnvVarError::Missing(key) => write!(f, "variable {key:?} is not set"),
+            EnvVarError::Parse(err) => write!(f, "variable could not be parsed: {err}"),
+        }
+    }
+}
+
+impl<E: fmt::Debug + fmt::Display> core::error::Error for EnvVarError<E> {}
+
+/// A `FallibleIso` between a string map and the parsed value of one of its entries.
+struct EnvVar<T> {
+    key: String,
+    phantom: PhantomData<T>,
+}
+
+impl<T: FromStr + ToString> HasGetter<BTreeMap<String, String>, T> for EnvVar<T> {
+    type GetterError = EnvVarError<T::Err>;
+
+    fn try_get(&self, source: &BTreeMap<String, String>) -> Result<T, Self::GetterError> {
+        source
+            .get(&self.key)
+            .ok_or_else(|| EnvVarError::Missing(self.key.clone()))?
+            .parse()
+            .map_err(EnvVarError::Parse)
+    }
+}
+
+impl<T: FromStr + ToString> HasSetter<BTreeMap<String, String>, T> for EnvVar<T> {
+    fn set(&self, source: &mut BTreeMap<String, String>, value: T) {
+        source.insert(self.key.clone(), value.to_string());
+    }
+}
+
+impl<T: FromStr + ToString> HasReverseGet<BTreeMap<String, String>, T> for EnvVar<T> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &T) -> Result<BTreeMap<String, String>, Self::ReverseError> {
+        Ok(BTreeMap::from([(self.key.clone(), value.to_string())]))
+    }
+}
+
+/// Creates a `FallibleIso` focusing on the entry at `key` of an environment-style string map,
+/// parsed as a `T`.
+///
+/// Reading fails with [`EnvVarError::Missing`] if the key is absent, and with
+/// [`EnvVarError::Parse`] if its value does not parse with [`FromStr`]. Setting renders the value
+/// with [`ToString`] and inserts it, leaving the other entries untouched. Reversing builds a map
+/// holding only that entry.
+///
+/// As with [`parse_iso`], the round trip only holds for values in the canonical rendering of `T`.
+///
+/// [`parse_iso`]: crate::parse_iso
+///
+/// # Examples
+///
+/// ```
+/// use optics::{EnvVarError, HasGetter, HasSetter, env_var};
+/// use std::collections::BTreeMap;
+///
+/// let mut env = BTreeMap::from([("PORT".to_string(), "8080".to_string())]);
+/// let port = env_var::<u16>("PORT");
+///
+/// assert_eq!(port.try_get(&env), Ok(8080));
+///
+/// port.set(&mut env, 9090);
+/// assert_eq!(env["PORT"], "9090");
+///
+/// let missing = env_var::<u16>("HOST").try_get(&env);
+/// assert_eq!(missing, Err(EnvVarError::Missing("HOST".to_string())));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: FromStr + ToString>(
+    key: &str,
+) -> FallibleIsoImpl<
+    BTreeMap<String, String>,
+    T,
+    impl FallibleIso<
+        BTreeMap<String, String>,
+        T,
+        GetterError = EnvVarError<T::Err>,
+        ReverseError = Infallible,
+    >,
+> {
+    FallibleIsoImpl::new(EnvVar {
+        key: key.to_string(),
+        phantom: PhantomData,
+    })
+}
@@ -0,0 +1,123 @@
+use crate::{
+    FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter, HasTotalReverseGet,
+};
+use alloc::string::String;
+use core::convert::Infallible;
+use core::fmt;
+
+/// The error returned by [`hex_iso`] when a string is not the hex encoding of `N` bytes.
+///
+/// [`hex_iso`]: crate::hex_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HexError {
+    /// The string does not have exactly `2 * N` characters.
+    Length {
+        /// The expected number of characters, `2 * N`.
+        expected: usize,
+        /// The actual number of characters.
+        found: usize,
+    },
+    /// The character at `index` is not a hexadecimal digit.
+    InvalidDigit {
+        /// The index of the offending character.
+        index: usize,
+        /// The offending character.
+        found: char,
+    },
+}
+
+impl fmt::Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HexError::Length { expected, found } => {
+                write!(f, "expected {expected} hex digits, found {found}")
+            }
+            HexError::InvalidDigit { index, found } => {
+                write!(f, "invalid hex digit {found:?} at index {index}")
+            }
+        }
+    }
+}
+
+impl core::error::Error for HexError {}
+
+/// A `FallibleIso` between a hex `String` and a byte array.
+struct Hex<const N: usize>;
+
+impl<const N: usize> HasGetter<String, [u8; N]> for Hex<N> {
+    type GetterError = HexError;
+
+    fn try_get(&self, source: &String) -> Result<[u8; N], Self::GetterError> {
+        let found = source.chars().count();
+        if found != 2 * N {
+            return Err(HexError::Length {
+                expected: 2 * N,
+                found,
+            });
+        }
+
+        let mut bytes = [0; N];
+        for (index, c) in source.chars().enumerate() {
+            let digit = c
+                .to_digit(16)
+                .ok_or(HexError::InvalidDigit { index, found: c })?;
+            // `to_digit(16)` is always below 16, so the cast is lossless.
+            #[allow(clippy::cast_possible_truncation)]
+            let digit = digit as u8;
+            bytes[index / 2] = (bytes[index / 2] << 4) | digit;
+        }
+        Ok(bytes)
+    }
+}
+
+impl<const N: usize> HasSetter<String, [u8; N]> for Hex<N> {
+    fn set(&self, source: &mut String, value: [u8; N]) {
+        *source = self.reverse_get(&value);
+    }
+}
+
+impl<const N: usize> HasReverseGet<String, [u8; N]> for Hex<N> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &[u8; N]) -> Result<String, Self::ReverseError> {
+        const DIGITS: &[u8; 16] = b"0123456789abcdef";
+
+        let mut hex = String::with_capacity(2 * N);
+        for byte in value {
+            hex.push(char::from(DIGITS[usize::from(byte >> 4)]));
+            hex.push(char::from(DIGITS[usize::from(byte & 0xf)]));
+        }
+        Ok(hex)
+    }
+}
+
+/// Creates a `FallibleIso` between a hex `String` and a `[u8; N]`.
+///
+/// Reading parses a string of exactly `2 * N` hexadecimal digits, in either case, two digits per
+/// byte with the most significant digit first. It fails with [`HexError::Length`] if the
+/// string has the wrong number of characters, and with [`HexError::InvalidDigit`] if one of
+/// them is not a hexadecimal digit. Reversing renders the bytes as lowercase hex and never fails.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasTotalReverseGet, HexError, hex_iso};
+///
+/// let hex = hex_iso::<2>();
+///
+/// assert_eq!(hex.try_get(&"C0fe".to_string()), Ok([0xc0, 0xfe]));
+/// assert_eq!(hex.reverse_get(&[0xc0, 0xfe]), "c0fe");
+/// assert_eq!(
+///     hex.try_get(&"c0f".to_string()),
+///     Err(HexError::Length { expected: 4, found: 3 })
+/// );
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<const N: usize>() -> FallibleIsoImpl<
+    String,
+    [u8; N],
+    impl FallibleIso<String, [u8; N], GetterError = HexError, ReverseError = Infallible>,
+> {
+    FallibleIsoImpl::new(Hex::<N>)
+}
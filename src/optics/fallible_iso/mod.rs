@@ -1,11 +1,46 @@
 use crate::{HasGetter, HasSetter};
 pub(crate) mod composed;
+#[cfg(feature = "alloc")]
+mod env_var;
+#[cfg(feature = "alloc")]
+mod hex;
 pub(crate) mod mapped;
+#[cfg(feature = "alloc")]
+mod parse;
+#[cfg(feature = "alloc")]
+mod permute;
+#[cfg(feature = "std")]
+mod ratio;
+mod reversed;
+#[cfg(feature = "alloc")]
+mod str_enum;
+#[cfg(feature = "std")]
+mod system_time;
+#[cfg(feature = "alloc")]
+mod trimmed;
+mod try_into;
 mod wrapper;
 
 use crate::HasReverseGet;
 pub use composed::new as composed_fallible_iso;
+#[cfg(feature = "alloc")]
+pub use env_var::{EnvVarError, new as env_var};
+#[cfg(feature = "alloc")]
+pub use hex::{HexError, new as hex_iso};
 pub use mapped::new as mapped_fallible_iso;
+#[cfg(feature = "alloc")]
+pub use parse::new as parse_iso;
+#[cfg(feature = "alloc")]
+pub use permute::{InvalidPermutation, new as permute_iso};
+#[cfg(feature = "std")]
+pub use ratio::{RatioError, new as ratio_iso};
+#[cfg(feature = "alloc")]
+pub use str_enum::{StrEnumError, new as str_enum_prism};
+#[cfg(feature = "std")]
+pub use system_time::{UnixTimeError, new as system_time_iso};
+#[cfg(feature = "alloc")]
+pub use trimmed::{BlankError, iso as non_empty_trimmed_iso, new as non_empty_trimmed_prism};
+pub use try_into::{OutOfRange, new as try_into_iso};
 pub use wrapper::FallibleIsoImpl;
 
 /// A bidirectional, fallible isomorphism between two types `S` and `A`.
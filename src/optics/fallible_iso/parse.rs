@@ -0,0 +1,36 @@
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+use alloc::string::{String, ToString};
+use core::convert::Infallible;
+use core::str::FromStr;
+
+/// Creates a `FallibleIso` between a `String` and any type that can be parsed from and rendered
+/// to a string.
+///
+/// Reading parses the string with [`FromStr`] and fails with the parser's error. Reversing renders
+/// the value with [`ToString`] and never fails. The round trip only holds for strings in the
+/// canonical rendering of `T`, e.g. `"+1"` parses to `1` but renders back as `"1"`.
+///
+/// Use [`FallibleIsoImpl::reverse`] to get the render-then-parse direction.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasReverseGet, parse_iso};
+///
+/// let iso = parse_iso::<i32>();
+///
+/// assert_eq!(iso.try_get(&"42".to_string()), Ok(42));
+/// assert!(iso.try_get(&"forty-two".to_string()).is_err());
+/// assert_eq!(iso.try_reverse_get(&7), Ok("7".to_string()));
+/// ```
+#[must_use]
+pub fn new<T: FromStr + ToString>() -> FallibleIsoImpl<
+    String,
+    T,
+    impl FallibleIso<String, T, GetterError = T::Err, ReverseError = Infallible>,
+> {
+    mapped_fallible_iso(
+        |s: &String| s.parse::<T>(),
+        |t: &T| Ok::<_, Infallible>(t.to_string()),
+    )
+}
@@ -0,0 +1,113 @@
+use crate::{FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// The error returned by [`permute_iso`] when its permutation is not a permutation of the
+/// indices of the vector it is applied to.
+///
+/// [`permute_iso`]: crate::permute_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvalidPermutation;
+
+impl fmt::Display for InvalidPermutation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("not a permutation of the vector indices")
+    }
+}
+
+impl core::error::Error for InvalidPermutation {}
+
+/// A `FallibleIso` reordering a `Vec` by a permutation of its indices.
+struct Permute<A> {
+    perm: Vec<usize>,
+    phantom: PhantomData<A>,
+}
+
+impl<A> Permute<A> {
+    fn check(&self, len: usize) -> Result<(), InvalidPermutation> {
+        if self.perm.len() != len {
+            return Err(InvalidPermutation);
+        }
+        let mut seen = vec![false; len];
+        for &i in &self.perm {
+            match seen.get_mut(i) {
+                Some(s) if !*s => *s = true,
+                _ => return Err(InvalidPermutation),
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<A: Clone> HasGetter<Vec<A>, Vec<A>> for Permute<A> {
+    type GetterError = InvalidPermutation;
+
+    fn try_get(&self, source: &Vec<A>) -> Result<Vec<A>, Self::GetterError> {
+        self.check(source.len())?;
+        Ok(self.perm.iter().map(|&i| source[i].clone()).collect())
+    }
+}
+
+impl<A: Clone> HasSetter<Vec<A>, Vec<A>> for Permute<A> {
+    fn set(&self, source: &mut Vec<A>, value: Vec<A>) {
+        if let Ok(s) = self.try_reverse_get(&value) {
+            *source = s;
+        }
+    }
+}
+
+impl<A: Clone> HasReverseGet<Vec<A>, Vec<A>> for Permute<A> {
+    type ReverseError = InvalidPermutation;
+
+    fn try_reverse_get(&self, value: &Vec<A>) -> Result<Vec<A>, Self::ReverseError> {
+        self.check(value.len())?;
+        let mut source: Vec<Option<A>> = vec![None; value.len()];
+        for (&i, a) in self.perm.iter().zip(value) {
+            source[i] = Some(a.clone());
+        }
+        Ok(source.into_iter().flatten().collect())
+    }
+}
+
+/// Creates a `FallibleIso` reordering a `Vec<A>` by the permutation `perm`.
+///
+/// Reading yields the vector whose `i`-th element is `source[perm[i]]`. Reversing applies the
+/// inverse permutation, putting the `i`-th element of the value back at index `perm[i]`.
+///
+/// Both directions fail with [`InvalidPermutation`] unless `perm` contains every index of the
+/// vector exactly once. The permutation is validated on every call, as its validity depends on
+/// the length of the vector it is applied to. Setting an invalid value leaves the source
+/// untouched.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasReverseGet, InvalidPermutation, permute_iso};
+///
+/// let rotate = permute_iso(vec![1, 2, 0]);
+///
+/// assert_eq!(rotate.try_get(&vec!['a', 'b', 'c']), Ok(vec!['b', 'c', 'a']));
+/// assert_eq!(rotate.try_reverse_get(&vec!['b', 'c', 'a']), Ok(vec!['a', 'b', 'c']));
+/// assert_eq!(rotate.try_get(&vec!['a', 'b']), Err(InvalidPermutation));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<A: Clone>(
+    perm: Vec<usize>,
+) -> FallibleIsoImpl<
+    Vec<A>,
+    Vec<A>,
+    impl FallibleIso<
+        Vec<A>,
+        Vec<A>,
+        GetterError = InvalidPermutation,
+        ReverseError = InvalidPermutation,
+    >,
+> {
+    FallibleIsoImpl::new(Permute {
+        perm,
+        phantom: PhantomData,
+    })
+}
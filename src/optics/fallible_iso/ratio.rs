@@ -0,0 +1,106 @@
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+use core::fmt;
+
+/// The largest denominator [`ratio_iso`] produces when approximating a float.
+///
+/// [`ratio_iso`]: crate::ratio_iso
+const MAX_DENOMINATOR: i64 = 1_000_000;
+
+/// The error returned by [`ratio_iso`] when converting between a fraction and a float fails.
+///
+/// [`ratio_iso`]: crate::ratio_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RatioError {
+    /// The fraction has a zero denominator, so it has no value.
+    ZeroDenominator,
+    /// The float is NaN, infinite, or too large in magnitude for an `i64` numerator.
+    NotRepresentable,
+}
+
+impl fmt::Display for RatioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RatioError::ZeroDenominator => f.write_str("fraction has a zero denominator"),
+            RatioError::NotRepresentable => {
+                f.write_str("float cannot be represented as a fraction")
+            }
+        }
+    }
+}
+
+impl core::error::Error for RatioError {}
+
+#[allow(clippy::cast_precision_loss)]
+fn divide(&(numerator, denominator): &(i64, i64)) -> Result<f64, RatioError> {
+    if denominator == 0 {
+        return Err(RatioError::ZeroDenominator);
+    }
+    Ok(numerator as f64 / denominator as f64)
+}
+
+/// Approximates `value` with the continued fraction convergents of its magnitude, stopping at
+/// the last convergent whose denominator does not exceed [`MAX_DENOMINATOR`].
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+fn approximate(value: f64) -> Result<(i64, i64), RatioError> {
+    let target = value.abs();
+    if !target.is_finite() || target >= i64::MAX as f64 {
+        return Err(RatioError::NotRepresentable);
+    }
+
+    let (mut p0, mut q0, mut p1, mut q1) = (0_i64, 1_i64, 1_i64, 0_i64);
+    let mut rest = target;
+    loop {
+        let whole = rest.floor();
+        let a = whole as i64;
+        let next = a
+            .checked_mul(p1)
+            .and_then(|p| p.checked_add(p0))
+            .zip(a.checked_mul(q1).and_then(|q| q.checked_add(q0)));
+        let Some((p2, q2)) = next.filter(|&(_, q2)| q2 <= MAX_DENOMINATOR) else {
+            break;
+        };
+        (p0, q0, p1, q1) = (p1, q1, p2, q2);
+
+        let fraction = rest - whole;
+        if fraction == 0.0 || (p1 as f64 / q1 as f64 - target).abs() <= f64::EPSILON * target {
+            break;
+        }
+        rest = 1.0 / fraction;
+    }
+
+    let numerator = if value.is_sign_negative() { -p1 } else { p1 };
+    Ok((numerator, q1))
+}
+
+/// Creates a `FallibleIso` between a fraction, as a `(numerator, denominator)` pair, and its
+/// value as an `f64`.
+///
+/// Reading divides the numerator by the denominator, and fails with
+/// [`RatioError::ZeroDenominator`] if the denominator is zero. Large numerators and denominators
+/// lose precision when converted to `f64`.
+///
+/// Reversing approximates the float with a fraction in lowest terms, using continued fractions.
+/// The result is the closest such fraction found with a denominator of at most one million, so
+/// floats holding a simple fraction such as `0.75` or `1.0 / 3.0` map back to it exactly, while
+/// other values are only approximated. The denominator is always positive. Reversing fails with
+/// [`RatioError::NotRepresentable`] for NaN, infinities, and magnitudes too large for an `i64`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasReverseGet, RatioError, ratio_iso};
+///
+/// let ratio = ratio_iso();
+///
+/// assert_eq!(ratio.try_get(&(3, 4)), Ok(0.75));
+/// assert_eq!(ratio.try_get(&(1, 0)), Err(RatioError::ZeroDenominator));
+/// assert_eq!(ratio.try_reverse_get(&-1.5), Ok((-3, 2)));
+/// ```
+#[must_use]
+pub fn new() -> FallibleIsoImpl<
+    (i64, i64),
+    f64,
+    impl FallibleIso<(i64, i64), f64, GetterError = RatioError, ReverseError = RatioError>,
+> {
+    mapped_fallible_iso(divide, |value: &f64| approximate(*value))
+}
@@ -0,0 +1,60 @@
+use crate::optics::fallible_iso::FallibleIso;
+use crate::optics::fallible_iso::wrapper::FallibleIsoImpl;
+use crate::{HasGetter, HasReverseGet, HasSetter};
+use core::marker::PhantomData;
+
+/// A `FallibleIso` that runs another fallible iso backwards.
+///
+/// The getter of the reversed optic is the reverse-getter of the inner one and vice versa, and
+/// the error types are swapped along with them.
+///
+/// # See Also
+/// - [`FallibleIsoImpl::reverse`] — the method that builds this optic.
+struct ReversedFallibleIso<S, A, FI: FallibleIso<S, A>> {
+    fallible_iso: FI,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, FI: FallibleIso<S, A>> ReversedFallibleIso<S, A, FI> {
+    fn new(fallible_iso: FI) -> Self {
+        ReversedFallibleIso {
+            fallible_iso,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A>> HasGetter<A, S> for ReversedFallibleIso<S, A, FI> {
+    type GetterError = FI::ReverseError;
+
+    fn try_get(&self, source: &A) -> Result<S, Self::GetterError> {
+        self.fallible_iso.try_reverse_get(source)
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A>> HasSetter<A, S> for ReversedFallibleIso<S, A, FI> {
+    fn set(&self, source: &mut A, value: S) {
+        if let Ok(a) = self.fallible_iso.try_get(&value) {
+            *source = a;
+        }
+    }
+}
+
+impl<S, A, FI: FallibleIso<S, A>> HasReverseGet<A, S> for ReversedFallibleIso<S, A, FI> {
+    type ReverseError = FI::GetterError;
+
+    fn try_reverse_get(&self, value: &S) -> Result<A, Self::ReverseError> {
+        self.fallible_iso.try_get(value)
+    }
+}
+
+#[must_use]
+pub fn new<S, A, FI: FallibleIso<S, A>>(
+    fallible_iso: FI,
+) -> FallibleIsoImpl<
+    A,
+    S,
+    impl FallibleIso<A, S, GetterError = FI::ReverseError, ReverseError = FI::GetterError>,
+> {
+    FallibleIsoImpl::new(ReversedFallibleIso::new(fallible_iso))
+}
@@ -0,0 +1,105 @@
+use crate::{FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter};
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The error returned by [`str_enum_prism`] when a value is missing from its mapping.
+///
+/// [`str_enum_prism`]: crate::str_enum_prism
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StrEnumError {
+    /// The string is not one of the mapped variant names.
+    UnknownString(String),
+    /// The value has no variant name in the mapping.
+    UnmappedValue,
+}
+
+impl fmt::Display for StrEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            StrEnumError::UnknownString(s) => write!(f, "unknown variant name {s:?}"),
+            StrEnumError::UnmappedValue => f.write_str("value has no variant name"),
+        }
+    }
+}
+
+impl core::error::Error for StrEnumError {}
+
+/// A `FallibleIso` between a `String` and the value it names in a fixed mapping.
+struct StrEnum<E> {
+    mapping: Vec<(&'static str, E)>,
+}
+
+impl<E: Clone + PartialEq> HasGetter<String, E> for StrEnum<E> {
+    type GetterError = StrEnumError;
+
+    fn try_get(&self, source: &String) -> Result<E, Self::GetterError> {
+        self.mapping
+            .iter()
+            .find(|(name, _)| name == source)
+            .map(|(_, value)| value.clone())
+            .ok_or_else(|| StrEnumError::UnknownString(source.clone()))
+    }
+}
+
+impl<E: Clone + PartialEq> HasSetter<String, E> for StrEnum<E> {
+    fn set(&self, source: &mut String, value: E) {
+        if let Ok(s) = self.try_reverse_get(&value) {
+            *source = s;
+        }
+    }
+}
+
+impl<E: Clone + PartialEq> HasReverseGet<String, E> for StrEnum<E> {
+    type ReverseError = StrEnumError;
+
+    fn try_reverse_get(&self, value: &E) -> Result<String, Self::ReverseError> {
+        self.mapping
+            .iter()
+            .find(|(_, v)| v == value)
+            .map(|(name, _)| name.to_string())
+            .ok_or(StrEnumError::UnmappedValue)
+    }
+}
+
+/// Creates a `FallibleIso` between a `String` and a value of `E`, using `mapping` to translate
+/// between variant names and values in both directions.
+///
+/// Reading looks up the string among the names and fails with
+/// [`StrEnumError::UnknownString`] if it is not mapped. Reversing looks up the value and fails
+/// with [`StrEnumError::UnmappedValue`] if it has no name. If a name or a value appears more
+/// than once, the first matching pair wins. Setting an unmapped value leaves the source
+/// untouched.
+///
+/// Being a `FallibleIso`, the result can be used wherever a `Prism` is expected.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasReverseGet, StrEnumError, str_enum_prism};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Level { Low, High }
+///
+/// let level = str_enum_prism(&[("low", Level::Low), ("high", Level::High)]);
+///
+/// assert_eq!(level.try_get(&"high".to_string()), Ok(Level::High));
+/// assert_eq!(level.try_reverse_get(&Level::Low), Ok("low".to_string()));
+/// assert_eq!(
+///     level.try_get(&"medium".to_string()),
+///     Err(StrEnumError::UnknownString("medium".to_string()))
+/// );
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<E: Clone + PartialEq>(
+    mapping: &[(&'static str, E)],
+) -> FallibleIsoImpl<
+    String,
+    E,
+    impl FallibleIso<String, E, GetterError = StrEnumError, ReverseError = StrEnumError>,
+> {
+    FallibleIsoImpl::new(StrEnum {
+        mapping: mapping.to_vec(),
+    })
+}
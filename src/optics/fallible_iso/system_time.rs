@@ -0,0 +1,70 @@
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+use core::fmt;
+use core::time::Duration;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// The error returned by [`system_time_iso`] when a time has no Unix timestamp.
+///
+/// [`system_time_iso`]: crate::system_time_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnixTimeError {
+    /// The time, or the timestamp, lies before the Unix epoch.
+    PreEpoch,
+    /// The time, or the timestamp, is too far from the Unix epoch to be represented.
+    Overflow,
+}
+
+impl fmt::Display for UnixTimeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UnixTimeError::PreEpoch => f.write_str("time is before the Unix epoch"),
+            UnixTimeError::Overflow => f.write_str("time is out of the representable range"),
+        }
+    }
+}
+
+impl core::error::Error for UnixTimeError {}
+
+fn to_seconds(time: &SystemTime) -> Result<i64, UnixTimeError> {
+    let elapsed = time
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| UnixTimeError::PreEpoch)?;
+    i64::try_from(elapsed.as_secs()).map_err(|_| UnixTimeError::Overflow)
+}
+
+fn from_seconds(seconds: i64) -> Result<SystemTime, UnixTimeError> {
+    let seconds = u64::try_from(seconds).map_err(|_| UnixTimeError::PreEpoch)?;
+    UNIX_EPOCH
+        .checked_add(Duration::from_secs(seconds))
+        .ok_or(UnixTimeError::Overflow)
+}
+
+/// Creates a `FallibleIso` between a [`SystemTime`] and its Unix timestamp, in whole seconds.
+///
+/// Reading counts the seconds elapsed since [`UNIX_EPOCH`], dropping any fraction of a second,
+/// and reversing adds that many seconds back to it. Both directions fail with
+/// [`UnixTimeError::PreEpoch`] for times or timestamps before the epoch, and with
+/// [`UnixTimeError::Overflow`] when the other side cannot represent the value. Only times
+/// falling on a whole second round-trip exactly.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasReverseGet, UnixTimeError, system_time_iso};
+/// use std::time::{Duration, UNIX_EPOCH};
+///
+/// let unix = system_time_iso();
+/// let time = UNIX_EPOCH + Duration::from_secs(1_700_000_000);
+///
+/// assert_eq!(unix.try_get(&time), Ok(1_700_000_000));
+/// assert_eq!(unix.try_reverse_get(&1_700_000_000), Ok(time));
+/// assert_eq!(unix.try_reverse_get(&-1), Err(UnixTimeError::PreEpoch));
+/// ```
+#[must_use]
+pub fn new() -> FallibleIsoImpl<
+    SystemTime,
+    i64,
+    impl FallibleIso<SystemTime, i64, GetterError = UnixTimeError, ReverseError = UnixTimeError>,
+> {
+    mapped_fallible_iso(to_seconds, |seconds: &i64| from_seconds(*seconds))
+}
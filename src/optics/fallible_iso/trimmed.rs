@@ -0,0 +1,114 @@
+use crate::{
+    FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl,
+    mapped_fallible_iso,
+};
+use alloc::string::String;
+use core::convert::Infallible;
+use core::fmt;
+
+/// The error returned by [`non_empty_trimmed_prism`] and [`non_empty_trimmed_iso`] when a string
+/// is empty or only holds whitespace.
+///
+/// [`non_empty_trimmed_prism`]: crate::non_empty_trimmed_prism
+/// [`non_empty_trimmed_iso`]: crate::non_empty_trimmed_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct BlankError;
+
+impl fmt::Display for BlankError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("string is blank")
+    }
+}
+
+impl core::error::Error for BlankError {}
+
+fn trimmed(s: &str) -> Result<String, BlankError> {
+    match s.trim() {
+        "" => Err(BlankError),
+        t => Ok(String::from(t)),
+    }
+}
+
+/// A `Prism` focusing on a string with its surrounding whitespace trimmed, if anything remains.
+struct NonEmptyTrimmed;
+
+impl HasGetter<String, String> for NonEmptyTrimmed {
+    type GetterError = BlankError;
+
+    fn try_get(&self, source: &String) -> Result<String, Self::GetterError> {
+        trimmed(source)
+    }
+}
+
+impl HasSetter<String, String> for NonEmptyTrimmed {
+    fn set(&self, source: &mut String, value: String) {
+        *source = String::from(value.trim());
+    }
+}
+
+impl HasReverseGet<String, String> for NonEmptyTrimmed {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &String) -> Result<String, Self::ReverseError> {
+        Ok(String::from(value.trim()))
+    }
+}
+
+/// Creates a `Prism` focusing on a string with its leading and trailing whitespace trimmed,
+/// failing with [`BlankError`] if nothing remains.
+///
+/// Setting and reversing store the trimmed value. This normalizes strings at input boundaries,
+/// but it also means the prism does not strictly obey the prism laws: reading `"  a "` gives
+/// `"a"`, which reverses to `"a"` rather than the original string. Use
+/// [`non_empty_trimmed_iso`] to also reject blank values when reversing.
+///
+/// [`non_empty_trimmed_iso`]: crate::non_empty_trimmed_iso
+///
+/// # Examples
+///
+/// ```
+/// use optics::{BlankError, HasGetter, non_empty_trimmed_prism};
+///
+/// let name = non_empty_trimmed_prism();
+///
+/// assert_eq!(name.try_get(&"  Ada ".to_string()), Ok("Ada".to_string()));
+/// assert_eq!(name.try_get(&" \t".to_string()), Err(BlankError));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new() -> PrismImpl<
+    String,
+    String,
+    impl Prism<String, String, GetterError = BlankError>
+    + HasReverseGet<String, String, ReverseError = Infallible>,
+> {
+    NonEmptyTrimmed.into()
+}
+
+/// Creates a `FallibleIso` between a string and its trimmed form, failing with [`BlankError`] in
+/// both directions if nothing remains after trimming.
+///
+/// This is the fallible iso variant of [`non_empty_trimmed_prism`], and normalizes in the same
+/// way: both directions trim, so only strings without surrounding whitespace round-trip
+/// exactly.
+///
+/// [`non_empty_trimmed_prism`]: crate::non_empty_trimmed_prism
+///
+/// # Examples
+///
+/// ```
+/// use optics::{BlankError, HasReverseGet, non_empty_trimmed_iso};
+///
+/// let name = non_empty_trimmed_iso();
+///
+/// assert_eq!(name.try_reverse_get(&" Ada".to_string()), Ok("Ada".to_string()));
+/// assert_eq!(name.try_reverse_get(&String::new()), Err(BlankError));
+/// ```
+#[must_use]
+pub fn iso() -> FallibleIsoImpl<
+    String,
+    String,
+    impl FallibleIso<String, String, GetterError = BlankError, ReverseError = BlankError>,
+> {
+    mapped_fallible_iso(|s: &String| trimmed(s), |s: &String| trimmed(s))
+}
@@ -0,0 +1,52 @@
+use crate::{FallibleIso, FallibleIsoImpl, mapped_fallible_iso};
+use core::convert::Infallible;
+use core::fmt;
+
+/// The error returned by [`try_into_iso`] when a value does not fit into the narrower type.
+///
+/// It carries the original, out-of-range value.
+///
+/// [`try_into_iso`]: crate::try_into_iso
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OutOfRange<A>(pub A);
+
+impl<A: fmt::Debug> fmt::Display for OutOfRange<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value {:?} is out of range of the target type", self.0)
+    }
+}
+
+impl<A: fmt::Debug> core::error::Error for OutOfRange<A> {}
+
+/// Creates a `FallibleIso` between a wide type `A` and a narrower type `B`.
+///
+/// Reading narrows the value with [`TryInto`], failing with [`OutOfRange`] holding the original
+/// value if it does not fit. Reversing widens the value with [`Into`] and never fails.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasTotalReverseGet, OutOfRange, try_into_iso};
+///
+/// let narrow = try_into_iso::<i64, i32>();
+///
+/// assert_eq!(narrow.try_get(&42), Ok(42));
+/// assert_eq!(narrow.try_get(&i64::MAX), Err(OutOfRange(i64::MAX)));
+/// assert_eq!(narrow.reverse_get(&-7), -7i64);
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<A, B>() -> FallibleIsoImpl<
+    A,
+    B,
+    impl FallibleIso<A, B, GetterError = OutOfRange<A>, ReverseError = Infallible>,
+>
+where
+    A: Clone + TryInto<B>,
+    B: Clone + Into<A>,
+{
+    mapped_fallible_iso(
+        |a: &A| a.clone().try_into().map_err(|_| OutOfRange(a.clone())),
+        |b: &B| Ok::<_, Infallible>(b.clone().into()),
+    )
+}
@@ -1,6 +1,8 @@
+use crate::optics::fallible_iso::reversed::new as reversed_fallible_iso;
 use crate::{
-    FallibleIso, HasGetter, HasReverseGet, HasSetter, Iso, IsoImpl, Lens, LensImpl, Prism,
-    PrismImpl, composed_fallible_iso, composed_prism, infallible,
+    FallibleIso, Getter, GetterImpl, HasGetter, HasReverseGet, HasSetter, Iso, IsoImpl, Lens,
+    LensImpl, PartialGetter, PartialGetterImpl, Prism, PrismImpl, composed_fallible_iso,
+    composed_partial_getter, composed_prism, infallible, mapped_getter,
 };
 use core::convert::identity;
 use core::marker::PhantomData;
@@ -14,6 +16,77 @@ impl<S, A, FI: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI> {
     }
 }
 
+impl<S, A, FI: FallibleIso<S, A>> FallibleIsoImpl<S, A, FI> {
+    /// Modifies the focus value with a fallible transformation, merging the errors of the optic
+    /// and of the transformation.
+    ///
+    /// The source is only updated if both the optic and `f` succeed. Otherwise it is left
+    /// unchanged, and the error of the optic, converted through `Into::into`, or the error of `f`
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the optic fails to focus on a value, or if `f` fails.
+    pub fn try_modify_with<E, F>(&self, source: &mut S, f: F) -> Result<(), E>
+    where
+        FI::GetterError: Into<E>,
+        F: FnOnce(A) -> Result<A, E>,
+    {
+        let value = f(self.try_get(source).map_err(Into::into)?)?;
+        self.set(source, value);
+        Ok(())
+    }
+
+    /// Turns this fallible iso into a read-only, total `GetterImpl<S, A>`, computing the focus
+    /// with `f` from the getter error whenever the forward conversion fails.
+    ///
+    /// Unlike a fixed default value, the fallback can depend on why the conversion failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, parse_iso};
+    ///
+    /// let port = parse_iso::<u16>().or_recover(|_| 8080);
+    ///
+    /// assert_eq!(port.get(&"443".to_string()), 443);
+    /// assert_eq!(port.get(&"https".to_string()), 8080);
+    /// ```
+    pub fn or_recover<F: Fn(FI::GetterError) -> A>(
+        self,
+        f: F,
+    ) -> GetterImpl<S, A, impl Getter<S, A>> {
+        mapped_getter(move |source: &S| self.0.try_get(source).unwrap_or_else(&f))
+    }
+
+    /// Reverses this fallible iso, turning a `FallibleIso<S, A>` into a `FallibleIso<A, S>`.
+    ///
+    /// The getter of the result is the reverse-getter of `self` and vice versa, so the new
+    /// `GetterError` is the old `ReverseError` and the new `ReverseError` is the old
+    /// `GetterError`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasGetter, HasReverseGet, parse_iso};
+    ///
+    /// let render = parse_iso::<i32>().reverse();
+    ///
+    /// assert_eq!(render.try_get(&42), Ok("42".to_string()));
+    /// assert_eq!(render.try_reverse_get(&"7".to_string()), Ok(7));
+    /// ```
+    #[must_use]
+    pub fn reverse(
+        self,
+    ) -> FallibleIsoImpl<
+        A,
+        S,
+        impl FallibleIso<A, S, GetterError = FI::ReverseError, ReverseError = FI::GetterError>,
+    > {
+        reversed_fallible_iso(self.0)
+    }
+}
+
 impl<S, A, FI: FallibleIso<S, A>> HasGetter<S, A> for FallibleIsoImpl<S, A, FI> {
     type GetterError = FI::GetterError;
 
@@ -56,6 +129,33 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
         composed_prism(self, other, error_mapper_1, error_mapper_2)
     }
 
+    pub fn compose_with_getter<A, G2: Getter<I, A>>(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = FI1::GetterError>> {
+        composed_partial_getter(self.0, other.0, identity, infallible)
+    }
+
+    pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+    where
+        FI1::GetterError: Into<E>,
+        PG2::GetterError: Into<E>,
+    {
+        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+    }
+
+    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+        error_mapper_1: fn(FI1::GetterError) -> E,
+        error_mapper_2: fn(PG2::GetterError) -> E,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
+        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+    }
+
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
         other: LensImpl<I, A, L2>,
@@ -95,7 +195,11 @@ impl<S, I, FI1: FallibleIso<S, I>> FallibleIsoImpl<S, I, FI1> {
     pub fn compose_with_iso<A, ISO2: Iso<I, A>>(
         self,
         other: IsoImpl<I, A, ISO2>,
-    ) -> FallibleIsoImpl<S, A, impl FallibleIso<S, A>> {
+    ) -> FallibleIsoImpl<
+        S,
+        A,
+        impl FallibleIso<S, A, GetterError = FI1::GetterError, ReverseError = FI1::ReverseError>,
+    > {
         composed_fallible_iso(self.0, other.0, identity, infallible, identity, infallible)
     }
 }
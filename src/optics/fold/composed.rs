@@ -0,0 +1,52 @@
+use crate::{Fold, FoldImpl, HasFold};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A composed `Fold` type, combining two folds into a single fold.
+///
+/// The focus values of the composition are the focus values of the second fold, within each
+/// focus value of the first one, in order.
+///
+/// This struct is automatically created by the `compose_with_fold` and `compose_with_traversal`
+/// methods of [`FoldImpl`], and cannot be constructed manually.
+struct ComposedFold<F1: HasFold<S, I>, F2: HasFold<I, A>, S, I, A> {
+    optic1: F1,
+    optic2: F2,
+    _phantom: PhantomData<(S, I, A)>,
+}
+
+impl<F1, F2, S, I, A> ComposedFold<F1, F2, S, I, A>
+where
+    F1: HasFold<S, I>,
+    F2: HasFold<I, A>,
+{
+    fn new(optic1: F1, optic2: F2) -> Self {
+        ComposedFold {
+            optic1,
+            optic2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<F1, F2, S, I, A> HasFold<S, A> for ComposedFold<F1, F2, S, I, A>
+where
+    F1: HasFold<S, I>,
+    F2: HasFold<I, A>,
+{
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        self.optic1
+            .try_fold(source, init, |acc, i| self.optic2.try_fold(&i, acc, &mut f))
+    }
+}
+
+#[must_use]
+pub fn new<S, A, I, F1: HasFold<S, I>, F2: HasFold<I, A>>(
+    f1: F1,
+    f2: F2,
+) -> FoldImpl<S, A, impl Fold<S, A>> {
+    ComposedFold::new(f1, f2).into()
+}
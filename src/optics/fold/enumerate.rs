@@ -0,0 +1,33 @@
+use crate::{Fold, FoldImpl, HasFold};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Fold` pairing every focus value of another fold with its position.
+struct Enumerate<S, A, FOLD: HasFold<S, A>> {
+    fold: FOLD,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, FOLD: HasFold<S, A>> HasFold<S, (usize, A)> for Enumerate<S, A, FOLD> {
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, (usize, A)) -> ControlFlow<B, B>,
+    {
+        let mut index = 0;
+        self.fold.try_fold(source, init, |acc, a| {
+            let result = f(acc, (index, a));
+            index += 1;
+            result
+        })
+    }
+}
+
+pub(crate) fn new<S, A, FOLD: HasFold<S, A>>(
+    fold: FOLD,
+) -> FoldImpl<S, (usize, A), impl Fold<S, (usize, A)>> {
+    Enumerate {
+        fold,
+        phantom: PhantomData,
+    }
+    .into()
+}
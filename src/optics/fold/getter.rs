@@ -0,0 +1,29 @@
+use crate::{HasFold, HasGetter};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// Adapts any optic with a getter (a `Getter`, `PartialGetter`, `Lens`, `Prism` or `Iso`) into a
+/// `Fold` with zero or one focus values, so that it can be composed with other folds.
+struct GetterFold<S, A, G: HasGetter<S, A>> {
+    getter: G,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, G: HasGetter<S, A>> HasFold<S, A> for GetterFold<S, A, G> {
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        match self.getter.try_get(source) {
+            Ok(value) => f(init, value),
+            Err(_) => ControlFlow::Continue(init),
+        }
+    }
+}
+
+pub(crate) fn from_getter<S, A, G: HasGetter<S, A>>(getter: G) -> impl HasFold<S, A> {
+    GetterFold {
+        getter,
+        phantom: PhantomData,
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{Fold, FoldImpl, HasFold};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A concrete implementation of the [`Fold`] trait, built from a closure.
+///
+/// The closure returns the focus values of a source, in order, as anything that can be turned
+/// into an iterator.
+struct MappedFold<S, A, GET, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+{
+    get_all_fn: GET,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, GET, ITER> MappedFold<S, A, GET, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+{
+    fn new(get_all_fn: GET) -> Self {
+        MappedFold {
+            get_all_fn,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET, ITER> HasFold<S, A> for MappedFold<S, A, GET, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+{
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in (self.get_all_fn)(source) {
+            acc = f(acc, a)?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+/// Creates a new `Fold` with the provided function returning the focus values of a source.
+///
+/// The returned iterator is consumed lazily, so folds that stop early do not compute the
+/// remaining focus values.
+///
+/// # Arguments
+///
+/// - `get_all_fn` — A function that returns the focus values of the source, in order.
+///
+/// # Returns
+///
+/// A new `FoldImpl` instance that can be used as a `Fold<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, mapped_fold};
+///
+/// let digits = mapped_fold(|n: &u32| n.to_string().chars().collect::<Vec<_>>());
+///
+/// assert_eq!(digits.get_all(&123), vec!['1', '2', '3']);
+/// ```
+#[must_use]
+pub fn new<S, A, GET, ITER>(get_all_fn: GET) -> FoldImpl<S, A, impl Fold<S, A>>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+{
+    MappedFold::new(get_all_fn).into()
+}
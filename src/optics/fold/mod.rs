@@ -0,0 +1,44 @@
+mod composed;
+pub(crate) mod enumerate;
+pub(crate) mod getter;
+mod mapped;
+#[cfg(feature = "alloc")]
+mod string;
+#[cfg(feature = "alloc")]
+mod windows;
+mod wrapper;
+
+use crate::HasFold;
+
+pub use composed::new as composed_fold;
+pub use mapped::new as mapped_fold;
+#[cfg(feature = "alloc")]
+pub use string::{lines as lines_fold, words as words_fold};
+#[cfg(feature = "alloc")]
+pub use windows::new as windows_fold;
+pub use wrapper::FoldImpl;
+
+/// A read-only optic for focusing on zero or more values within a larger structure.
+///
+/// A `Fold` is the read-only counterpart of a [`Traversal`]: all focus values can be read, in
+/// order, through [`HasFold`], but they cannot be modified. This makes it suitable for focus
+/// values that are computed from the source, and have no place in it to be written back to.
+///
+/// Every `Traversal` is also a `Fold`.
+///
+/// # See Also
+///
+/// - [`Traversal`] — optic for reading and modifying multiple focus values
+/// - [`Getter`] — read-only optic for a single focus value
+///
+/// [`Traversal`]: crate::Traversal
+/// [`Getter`]: crate::Getter
+pub trait Fold<S, A>: HasFold<S, A> {}
+
+impl<S, A, FOLD: HasFold<S, A>> Fold<S, A> for FOLD {}
+
+/// Creates a `Fold` that focuses on the source itself, as its only focus value.
+#[must_use]
+pub fn identity_fold<S: Clone>() -> FoldImpl<S, S, impl Fold<S, S>> {
+    mapped_fold(|source: &S| core::iter::once(source.clone()))
+}
@@ -0,0 +1,76 @@
+use crate::{Fold, FoldImpl, HasFold};
+use alloc::string::String;
+use core::ops::ControlFlow;
+
+/// A `Fold` focusing on every line of a `String`.
+struct Lines;
+
+impl HasFold<String, String> for Lines {
+    fn try_fold<B, F>(&self, source: &String, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, String) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for line in source.lines() {
+            acc = f(acc, String::from(line))?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+/// A `Fold` focusing on every whitespace-separated word of a `String`.
+struct Words;
+
+impl HasFold<String, String> for Words {
+    fn try_fold<B, F>(&self, source: &String, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, String) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for word in source.split_whitespace() {
+            acc = f(acc, String::from(word))?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+/// Creates a `Fold` focusing on every line of a `String`, as split by [`str::lines`].
+///
+/// Line terminators, either `\n` or `\r\n`, are not part of the focus values.
+///
+/// # Allocation
+///
+/// Each line is cloned into a newly allocated `String` as it is visited.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, lines_fold};
+///
+/// let text = "first\nsecond\n".to_string();
+/// assert_eq!(lines_fold().get_all(&text), vec!["first", "second"]);
+/// ```
+#[must_use]
+pub fn lines() -> FoldImpl<String, String, impl Fold<String, String>> {
+    Lines.into()
+}
+
+/// Creates a `Fold` focusing on every whitespace-separated word of a `String`, as split by
+/// [`str::split_whitespace`].
+///
+/// # Allocation
+///
+/// Each word is cloned into a newly allocated `String` as it is visited.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, words_fold};
+///
+/// let text = "  hello \t world ".to_string();
+/// assert_eq!(words_fold().get_all(&text), vec!["hello", "world"]);
+/// ```
+#[must_use]
+pub fn words() -> FoldImpl<String, String, impl Fold<String, String>> {
+    Words.into()
+}
@@ -0,0 +1,65 @@
+use crate::{Fold, FoldImpl, HasFold};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Fold` focusing on every overlapping window of a `Vec`.
+struct Windows<A> {
+    size: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasFold<Vec<A>, Vec<A>> for Windows<A> {
+    fn try_fold<B, F>(&self, source: &Vec<A>, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, Vec<A>) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for window in source.windows(self.size) {
+            acc = f(acc, window.to_vec())?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+/// Creates a `Fold` focusing on every contiguous window of `size` elements of a `Vec`, as
+/// produced by [`slice::windows`].
+///
+/// Windows overlap: consecutive windows share all but one element, so a source of `len`
+/// elements has `len - size + 1` windows, and none at all when `size > len`.
+///
+/// This is a `Fold` rather than a `Traversal` because the windows cannot be written back
+/// independently. Every element but the outermost ones belongs to several windows, so setting
+/// one window would overwrite part of its neighbours, and the result would depend on the order
+/// in which they were written.
+///
+/// # Panics
+///
+/// Panics if `size` is zero.
+///
+/// # Allocation
+///
+/// Each window is cloned into a newly allocated `Vec` as it is visited.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, windows_fold};
+///
+/// let prices = vec![1.0, 2.0, 3.0, 5.0];
+/// let averages: Vec<f64> = windows_fold(2)
+///     .get_all(&prices)
+///     .iter()
+///     .map(|w| w.iter().sum::<f64>() / 2.0)
+///     .collect();
+/// assert_eq!(averages, vec![1.5, 2.5, 4.0]);
+/// ```
+#[must_use]
+pub fn new<A: Clone>(size: usize) -> FoldImpl<Vec<A>, Vec<A>, impl Fold<Vec<A>, Vec<A>>> {
+    assert!(size != 0, "windows_fold requires a non-zero size");
+    Windows {
+        size,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,95 @@
+use crate::optics::fold::enumerate::new as enumerated_fold;
+use crate::{Fold, HasFold, Traversal, TraversalImpl, composed_fold};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A wrapper of the [`Fold`] optic implementations, encapsulating a fold.
+///
+/// `FoldImpl` provides a common interface for folds, forwarding the fold operation to the
+/// wrapped implementation.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type of the optic
+/// - `A`: The type of the focus values
+/// - `FOLD`: The wrapped fold implementation
+///
+/// # See Also
+///
+/// - [`Fold`] — trait that `FoldImpl` implements
+pub struct FoldImpl<S, A, FOLD: Fold<S, A>>(pub FOLD, PhantomData<(S, A)>);
+
+impl<S, A, FOLD: Fold<S, A>> FoldImpl<S, A, FOLD> {
+    fn new(f: FOLD) -> Self {
+        //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
+        FoldImpl(f, PhantomData)
+    }
+}
+
+impl<S, A, FOLD: Fold<S, A>> From<FOLD> for FoldImpl<S, A, FOLD> {
+    fn from(value: FOLD) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, A, FOLD: Fold<S, A>> HasFold<S, A> for FoldImpl<S, A, FOLD> {
+    fn try_fold<B, F>(&self, source: &S, init: B, f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        self.0.try_fold(source, init, f)
+    }
+}
+
+impl<S, A, FOLD: Fold<S, A>> FoldImpl<S, A, FOLD> {
+    /// Pairs every focus value with its 0-based position, in the order of [`HasFold::get_all`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, words_fold};
+    ///
+    /// let text = "to be".to_string();
+    /// assert_eq!(
+    ///     words_fold().enumerate().get_all(&text),
+    ///     vec![(0, "to".to_string()), (1, "be".to_string())]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn enumerate(self) -> FoldImpl<S, (usize, A), impl Fold<S, (usize, A)>> {
+        enumerated_fold(self.0)
+    }
+
+    /// Composes this `FoldImpl<S, A>` with a `Fold<A, B>`, resulting in a new `FoldImpl<S, B>`
+    /// focusing on every focus value of `other` within every focus value of `self`, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, mapped_fold};
+    ///
+    /// let rows = mapped_fold(|grid: &Vec<Vec<u32>>| grid.clone());
+    /// let cells = mapped_fold(|row: &Vec<u32>| row.clone());
+    ///
+    /// let grid = vec![vec![1, 2], vec![], vec![3]];
+    /// assert_eq!(rows.compose_with_fold(cells).get_all(&grid), vec![1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn compose_with_fold<B, FOLD2: Fold<A, B>>(
+        self,
+        other: FoldImpl<A, B, FOLD2>,
+    ) -> FoldImpl<S, B, impl Fold<S, B>> {
+        composed_fold(self.0, other.0)
+    }
+
+    /// Composes this `FoldImpl<S, A>` with a `Traversal<A, B>`, resulting in a new read-only
+    /// `FoldImpl<S, B>` focusing on every focus value of `other` within every focus value of
+    /// `self`, in order.
+    #[must_use]
+    pub fn compose_with_traversal<B, T2: Traversal<A, B>>(
+        self,
+        other: TraversalImpl<A, B, T2>,
+    ) -> FoldImpl<S, B, impl Fold<S, B>> {
+        composed_fold(self.0, other.0)
+    }
+}
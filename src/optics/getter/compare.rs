@@ -0,0 +1,21 @@
+use crate::{Getter, HasTotalGetter};
+use core::cmp::Ordering;
+
+/// Creates a comparator ordering sources by the focus value of a getter.
+///
+/// The returned closure can be passed directly to `sort_by` and similar methods, to order a
+/// collection by a possibly deeply nested field. Any optic with an infallible getter can be
+/// used, including lenses and isos.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{compare_by, mapped_getter};
+///
+/// let mut words = vec!["ccc", "a", "bb"];
+/// words.sort_by(compare_by(mapped_getter(|w: &&str| w.len())));
+/// assert_eq!(words, vec!["a", "bb", "ccc"]);
+/// ```
+pub fn new<S, A: Ord, G: Getter<S, A>>(getter: G) -> impl Fn(&S, &S) -> Ordering {
+    move |left, right| getter.get(left).cmp(&getter.get(right))
+}
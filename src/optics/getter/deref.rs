@@ -0,0 +1,28 @@
+use crate::{Getter, GetterImpl, HasTotalGetter, mapped_getter};
+use core::ops::Deref;
+
+/// Lifts a `Getter<T, A>` to a `Getter<S, A>` over any pointer `S` dereferencing to `T`.
+///
+/// This is the read-only counterpart of [`deref_lift`], and only needs `S` to implement
+/// [`Deref`], so it also works for shared pointers like `Rc` and `Arc`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, deref_lift_getter, mapped_getter};
+/// use std::rc::Rc;
+///
+/// struct Node { value: i32 }
+///
+/// let value = deref_lift_getter(mapped_getter(|n: &Node| n.value));
+///
+/// assert_eq!(value.get(&Rc::new(Node { value: 1 })), 1);
+/// ```
+///
+/// [`deref_lift`]: crate::deref_lift
+#[must_use]
+pub fn new<S: Deref<Target = T>, T, A, G: Getter<T, A>>(
+    getter: GetterImpl<T, A, G>,
+) -> GetterImpl<S, A, impl Getter<S, A>> {
+    mapped_getter(move |source: &S| getter.get(source))
+}
@@ -0,0 +1,24 @@
+use crate::{Getter, GetterImpl, mapped_getter};
+use core::mem::Discriminant;
+
+/// Creates a `Getter` focusing on the discriminant of an enum, as returned by
+/// [`core::mem::discriminant`].
+///
+/// This allows checking which variant a value is without destructuring it, as discriminants
+/// implement `Eq` and `Hash`. A discriminant carries no payload, so there is no meaningful way
+/// to set it, and the optic is read-only.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, discriminant_getter};
+///
+/// let variant = discriminant_getter::<Option<u8>>();
+///
+/// assert_eq!(variant.get(&Some(1)), variant.get(&Some(2)));
+/// assert_ne!(variant.get(&Some(1)), variant.get(&None));
+/// ```
+#[must_use]
+pub fn new<E>() -> GetterImpl<E, Discriminant<E>, impl Getter<E, Discriminant<E>>> {
+    mapped_getter(core::mem::discriminant)
+}
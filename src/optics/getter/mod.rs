@@ -1,11 +1,21 @@
+mod compare;
 mod composed;
+mod deref;
+mod discriminant;
 mod mapped;
+#[cfg(feature = "alloc")]
+mod string_len;
 mod wrapper;
 
 use crate::HasGetter;
+pub use compare::new as compare_by;
 pub use composed::new as composed_getter;
 use core::convert::Infallible;
+pub use deref::new as deref_lift_getter;
+pub use discriminant::new as discriminant_getter;
 pub use mapped::new as mapped_getter;
+#[cfg(feature = "alloc")]
+pub use string_len::{byte_len as string_byte_len_getter, char_count as string_char_count_getter};
 pub use wrapper::GetterImpl;
 
 /// A `Getter` is an optic that focuses on a potential value inside a sum type.
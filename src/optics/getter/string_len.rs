@@ -0,0 +1,45 @@
+use crate::{Getter, GetterImpl, mapped_getter};
+use alloc::string::String;
+
+/// Creates a `Getter` focusing on the number of `char`s in a `String`.
+///
+/// This counts Unicode scalar values rather than bytes or grapheme clusters, which makes it
+/// suitable for user-facing length limits such as "a name must be at most 20 characters".
+///
+/// # Performance
+///
+/// Counting chars decodes the whole string, so each `get` is O(n) in the length of the string.
+/// Use [`string_byte_len_getter`] when the byte length is enough, as it is O(1).
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, string_char_count_getter};
+///
+/// let chars = string_char_count_getter();
+///
+/// assert_eq!(chars.get(&"naïve".to_string()), 5);
+/// ```
+///
+/// [`string_byte_len_getter`]: crate::string_byte_len_getter
+#[must_use]
+pub fn char_count() -> GetterImpl<String, usize, impl Getter<String, usize>> {
+    mapped_getter(|s: &String| s.chars().count())
+}
+
+/// Creates a `Getter` focusing on the length of a `String` in bytes, as returned by
+/// [`String::len`].
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, string_byte_len_getter};
+///
+/// let bytes = string_byte_len_getter();
+///
+/// assert_eq!(bytes.get(&"naïve".to_string()), 6);
+/// ```
+#[must_use]
+pub fn byte_len() -> GetterImpl<String, usize, impl Getter<String, usize>> {
+    mapped_getter(String::len)
+}
@@ -1,8 +1,16 @@
+#[cfg(feature = "alloc")]
+use crate::mapped_getter;
+use crate::optics::fold::getter::from_getter;
+use crate::optics::getter::compare::new as compare_by;
 use crate::optics::getter::composed::new as composed_getter;
 use crate::{
-    FallibleIso, FallibleIsoImpl, Getter, HasGetter, HasTotalGetter, Iso, IsoImpl, Lens, LensImpl,
-    PartialGetter, PartialGetterImpl, Prism, PrismImpl, composed_partial_getter, infallible,
+    FallibleIso, FallibleIsoImpl, Fold, FoldImpl, Getter, HasGetter, HasTotalGetter, Iso, IsoImpl,
+    Lens, LensImpl, PartialGetter, PartialGetterImpl, Prism, PrismImpl, composed_fold,
+    composed_partial_getter, infallible,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::cmp::Ordering;
 use core::convert::{Infallible, identity};
 use core::marker::PhantomData;
 
@@ -37,6 +45,13 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
         composed_getter(self.0, other.0)
     }
 
+    pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        composed_partial_getter(self, other.0, infallible, identity)
+    }
+
     pub fn compose_with_prism<A, P2: Prism<I, A>>(
         self,
         other: PrismImpl<I, A, P2>,
@@ -64,4 +79,53 @@ impl<S, I, G1: Getter<S, I>> GetterImpl<S, I, G1> {
     ) -> GetterImpl<S, A, impl Getter<S, A>> {
         composed_getter(self, other.0)
     }
+
+    /// Composes this `GetterImpl<S, I>` with a `Fold<I, A>`, resulting in a read-only
+    /// `FoldImpl<S, A>` focusing on every focus value of `other` within the focus of `self`.
+    pub fn compose_with_fold<A, F2: Fold<I, A>>(
+        self,
+        other: FoldImpl<I, A, F2>,
+    ) -> FoldImpl<S, A, impl Fold<S, A>> {
+        composed_fold(from_getter(self.0), other.0)
+    }
+}
+
+impl<S, A: Ord, G: Getter<S, A>> GetterImpl<S, A, G> {
+    /// Turns this getter into a comparator ordering sources by their focus value.
+    ///
+    /// See [`crate::compare_by`] for details.
+    pub fn comparator(self) -> impl Fn(&S, &S) -> Ordering {
+        compare_by(self.0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, A, G: Getter<S, Vec<A>>> GetterImpl<S, Vec<A>, G> {
+    /// Maps every element of the focused `Vec` with `f`, resulting in a `GetterImpl<S, Vec<B>>`
+    /// focusing on the collected results, in order.
+    ///
+    /// This is a shorthand for reading a collection and then a value out of each element, without
+    /// going through a [`Fold`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, mapped_getter};
+    ///
+    /// struct Team { members: Vec<(String, u32)> }
+    ///
+    /// let names = mapped_getter(|t: &Team| t.members.clone()).map_collect(|m| m.0.clone());
+    ///
+    /// let team = Team { members: vec![("ada".to_string(), 36), ("alan".to_string(), 41)] };
+    /// assert_eq!(names.get(&team), vec!["ada", "alan"]);
+    /// ```
+    pub fn map_collect<B, F: Fn(&A) -> B>(
+        self,
+        f: F,
+    ) -> GetterImpl<S, Vec<B>, impl Getter<S, Vec<B>>> {
+        composed_getter(
+            self,
+            mapped_getter(move |v: &Vec<A>| v.iter().map(&f).collect()).0,
+        )
+    }
 }
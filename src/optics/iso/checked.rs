@@ -0,0 +1,88 @@
+use crate::optics::iso::Iso;
+use crate::optics::iso::wrapper::IsoImpl;
+use crate::{HasGetter, HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// An `Iso` wrapper that verifies the round-trip law on every `get`.
+///
+/// Each call to `try_get` computes the focus, reverses it back into a source, and asserts that
+/// the result equals the original source, i.e. `reverse_get(get(s)) == s`. This is meant as a
+/// safety net to catch incorrectly defined isos early, typically while running tests.
+///
+/// The assertion is only compiled in when `debug_assertions` are enabled, or when the
+/// `law-checks` feature is turned on. Otherwise the wrapper simply forwards to the inner iso.
+///
+/// # Panics
+///
+/// `try_get` panics if the wrapped iso violates the round-trip law for the given source.
+///
+/// # See Also
+/// - [`Iso`] — the optic type being checked.
+struct CheckedIso<S, A, ISO: Iso<S, A>>
+where
+    S: PartialEq,
+{
+    iso: ISO,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, ISO> CheckedIso<S, A, ISO>
+where
+    S: PartialEq,
+    ISO: Iso<S, A>,
+{
+    fn new(iso: ISO) -> Self {
+        CheckedIso {
+            iso,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, ISO> HasGetter<S, A> for CheckedIso<S, A, ISO>
+where
+    S: PartialEq,
+    ISO: Iso<S, A>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let value = self.iso.get(source);
+
+        #[cfg(any(debug_assertions, feature = "law-checks"))]
+        assert!(
+            self.iso.reverse_get(&value) == *source,
+            "Iso round-trip law violated: reverse_get(get(s)) != s"
+        );
+
+        Ok(value)
+    }
+}
+
+impl<S, A, ISO> HasSetter<S, A> for CheckedIso<S, A, ISO>
+where
+    S: PartialEq,
+    ISO: Iso<S, A>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.iso.set(source, value);
+    }
+}
+
+impl<S, A, ISO> HasReverseGet<S, A> for CheckedIso<S, A, ISO>
+where
+    S: PartialEq,
+    ISO: Iso<S, A>,
+{
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.iso.try_reverse_get(value)
+    }
+}
+
+#[must_use]
+pub fn new<S: PartialEq, A, ISO: Iso<S, A>>(iso: ISO) -> IsoImpl<S, A, impl Iso<S, A>> {
+    CheckedIso::new(iso).into()
+}
@@ -84,7 +84,7 @@ where
     type ReverseError = Infallible;
 
     fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
-        Ok(self.reverse_get(value))
+        Ok(self.optic1.reverse_get(&self.optic2.reverse_get(value)))
     }
 }
 
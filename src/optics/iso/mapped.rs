@@ -96,6 +96,8 @@ where
     /// );
     ///
     // ```
+    // The second example is commented out, so its code is linted as prose.
+    #[allow(clippy::doc_markdown)]
     pub(crate) fn new(get_fn: GET, rev_fn: REV) -> Self {
         MappedIso {
             get_fn,
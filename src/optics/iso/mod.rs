@@ -1,12 +1,29 @@
 use crate::{HasGetter, HasReverseGet, HasSetter};
 use core::convert::Infallible;
 
+mod checked;
 mod composed;
 mod mapped;
+mod option_default;
+mod reversed;
+mod rotate;
+mod transpose;
+mod units;
+#[cfg(feature = "alloc")]
+mod unzip;
 mod wrapper;
 
 pub use composed::new as composed_iso;
 pub use mapped::new as mapped_iso;
+pub use option_default::new as option_default_iso;
+pub use rotate::new as rotate_iso;
+pub use transpose::new as transpose_iso;
+pub use units::{
+    celsius_fahrenheit as celsius_fahrenheit_iso, meters_feet as meters_feet_iso,
+    radians_degrees as radians_degrees_iso,
+};
+#[cfg(feature = "alloc")]
+pub use unzip::new as unzip_iso;
 pub use wrapper::IsoImpl;
 
 /// An isomorphism between two types `S` and `A`.
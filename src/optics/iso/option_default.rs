@@ -0,0 +1,35 @@
+use crate::{Iso, IsoImpl, mapped_iso};
+
+/// Creates an `Iso` between an `Option<A>` and `A`, treating `None` as `A::default()`.
+///
+/// Reading yields the inner value, or `A::default()` for `None`. Reversing maps the default
+/// value back to `None` and wraps every other value in `Some`.
+///
+/// # Collapse of `Some(default)` and `None`
+///
+/// This is only a genuine iso if `Some(A::default())` and `None` are considered equal:
+/// `reverse_get(get(Some(A::default())))` is `None`, not `Some(A::default())`. The other
+/// direction, `get(reverse_get(a)) == a`, always holds. Note that a lens with the same getter
+/// and setter would violate its laws for exactly the same sources, so the optic is offered as an
+/// iso, usable wherever `Some(A::default())` never occurs or is meant to be normalized to
+/// `None`. Wrapping it with [`IsoImpl::checked`] will panic on `Some(A::default())`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalReverseGet, option_default_iso};
+///
+/// let iso = option_default_iso::<i32>();
+///
+/// assert_eq!(iso.get(&Some(3)), 3);
+/// assert_eq!(iso.get(&None), 0);
+/// assert_eq!(iso.reverse_get(&3), Some(3));
+/// assert_eq!(iso.reverse_get(&0), None);
+/// ```
+#[must_use]
+pub fn new<A: Default + PartialEq + Clone>() -> IsoImpl<Option<A>, A, impl Iso<Option<A>, A>> {
+    mapped_iso(
+        |source: &Option<A>| source.clone().unwrap_or_default(),
+        |value: &A| (*value != A::default()).then(|| value.clone()),
+    )
+}
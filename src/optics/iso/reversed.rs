@@ -0,0 +1,52 @@
+use crate::optics::iso::Iso;
+use crate::optics::iso::wrapper::IsoImpl;
+use crate::{HasGetter, HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// An `Iso` that runs another iso backwards.
+///
+/// The getter of the reversed optic is the reverse-getter of the inner one and vice versa.
+///
+/// # See Also
+/// - [`IsoImpl::then_reverse`] — the method composing an iso with a reversed one.
+struct ReversedIso<S, A, ISO: Iso<S, A>> {
+    iso: ISO,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, ISO: Iso<S, A>> ReversedIso<S, A, ISO> {
+    fn new(iso: ISO) -> Self {
+        ReversedIso {
+            iso,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, ISO: Iso<S, A>> HasGetter<A, S> for ReversedIso<S, A, ISO> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &A) -> Result<S, Self::GetterError> {
+        Ok(self.iso.reverse_get(source))
+    }
+}
+
+impl<S, A, ISO: Iso<S, A>> HasSetter<A, S> for ReversedIso<S, A, ISO> {
+    fn set(&self, source: &mut A, value: S) {
+        *source = self.iso.get(&value);
+    }
+}
+
+impl<S, A, ISO: Iso<S, A>> HasReverseGet<A, S> for ReversedIso<S, A, ISO> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &S) -> Result<A, Self::ReverseError> {
+        Ok(self.iso.get(value))
+    }
+}
+
+#[must_use]
+pub fn new<S, A, ISO: Iso<S, A>>(iso: ISO) -> IsoImpl<A, S, impl Iso<A, S>> {
+    ReversedIso::new(iso).into()
+}
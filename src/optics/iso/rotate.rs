@@ -0,0 +1,38 @@
+use crate::{Iso, IsoImpl, mapped_iso};
+
+/// Creates an `Iso` viewing an array rotated to the left by `by` positions.
+///
+/// Reading through the iso yields the rotated arrangement, while reversing it (and thus setting
+/// through it) rotates the value back into the canonical arrangement. This is useful when a
+/// domain uses a rotated convention, but storage is kept in the canonical one.
+///
+/// The offset is taken modulo `N`, so rotating by `N` or more wraps around.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalReverseGet, rotate_iso};
+///
+/// let rotate = rotate_iso::<u8, 4>(1);
+///
+/// assert_eq!(rotate.get(&[1, 2, 3, 4]), [2, 3, 4, 1]);
+/// assert_eq!(rotate.reverse_get(&[2, 3, 4, 1]), [1, 2, 3, 4]);
+/// ```
+#[must_use]
+pub fn new<A: Clone, const N: usize>(
+    by: usize,
+) -> IsoImpl<[A; N], [A; N], impl Iso<[A; N], [A; N]>> {
+    let by = by.checked_rem(N).unwrap_or(0);
+    mapped_iso(
+        move |source: &[A; N]| {
+            let mut rotated = source.clone();
+            rotated.rotate_left(by);
+            rotated
+        },
+        move |value: &[A; N]| {
+            let mut source = value.clone();
+            source.rotate_right(by);
+            source
+        },
+    )
+}
@@ -0,0 +1,34 @@
+use crate::{Iso, IsoImpl, mapped_iso};
+
+/// Creates an `Iso` between an `Option` of a `Result` and a `Result` of an `Option`.
+///
+/// Reading uses [`Option::transpose`] and reversing uses [`Result::transpose`]:
+///
+/// - `None` maps to `Ok(None)`,
+/// - `Some(Ok(t))` maps to `Ok(Some(t))`, and
+/// - `Some(Err(e))` maps to `Err(e)`.
+///
+/// Every shape has exactly one counterpart, so the two directions are each other's inverse.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalReverseGet, transpose_iso};
+///
+/// let transpose = transpose_iso::<u8, &str>();
+///
+/// assert_eq!(transpose.get(&Some(Ok(1))), Ok(Some(1)));
+/// assert_eq!(transpose.reverse_get(&Err("boom")), Some(Err("boom")));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: Clone, E: Clone>() -> IsoImpl<
+    Option<Result<T, E>>,
+    Result<Option<T>, E>,
+    impl Iso<Option<Result<T, E>>, Result<Option<T>, E>>,
+> {
+    mapped_iso(
+        |source: &Option<Result<T, E>>| source.clone().transpose(),
+        |value: &Result<Option<T>, E>| value.clone().transpose(),
+    )
+}
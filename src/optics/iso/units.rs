@@ -0,0 +1,70 @@
+use crate::{Iso, IsoImpl, mapped_iso};
+
+/// Defines a function returning an `Iso<f64, f64>` for the linear conversion
+/// `to = from * $scale + $offset`, reversed as `from = (to - $offset) / $scale`.
+macro_rules! linear_iso {
+    ($(#[$attr:meta])* $name:ident, scale: $scale:expr, offset: $offset:expr) => {
+        $(#[$attr])*
+        ///
+        /// # Precision
+        ///
+        /// The conversion is computed with `f64` arithmetic, so a round trip may differ from the
+        /// original value by a few units in the last place, and equality checks on converted
+        /// values should use a tolerance.
+        #[must_use]
+        pub fn $name() -> IsoImpl<f64, f64, impl Iso<f64, f64>> {
+            mapped_iso(
+                |from: &f64| from * $scale + $offset,
+                |to: &f64| (to - $offset) / $scale,
+            )
+        }
+    };
+}
+
+linear_iso!(
+    /// Creates an `Iso` converting a temperature from degrees Celsius to degrees Fahrenheit.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, HasTotalReverseGet, celsius_fahrenheit_iso};
+    ///
+    /// assert_eq!(celsius_fahrenheit_iso().get(&100.0), 212.0);
+    /// assert_eq!(celsius_fahrenheit_iso().reverse_get(&32.0), 0.0);
+    /// ```
+    celsius_fahrenheit,
+    scale: 1.8,
+    offset: 32.0
+);
+
+linear_iso!(
+    /// Creates an `Iso` converting an angle from radians to degrees.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, radians_degrees_iso};
+    ///
+    /// assert_eq!(radians_degrees_iso().get(&core::f64::consts::PI), 180.0);
+    /// ```
+    radians_degrees,
+    scale: 180.0 / core::f64::consts::PI,
+    offset: 0.0
+);
+
+linear_iso!(
+    /// Creates an `Iso` converting a length from meters to international feet, of exactly
+    /// 0.3048 meters each.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalReverseGet, meters_feet_iso};
+    ///
+    /// let meters = meters_feet_iso().reverse_get(&10.0);
+    /// assert!((meters - 3.048).abs() < 1e-12);
+    /// ```
+    meters_feet,
+    scale: 1.0 / 0.3048,
+    offset: 0.0
+);
@@ -0,0 +1,34 @@
+use crate::{Iso, IsoImpl, mapped_iso};
+use alloc::vec::Vec;
+
+/// Creates an `Iso` between a `Vec` of pairs and the pair of `Vec`s of their components.
+///
+/// Reading unzips the pairs into two vectors of equal length, and reversing zips them back.
+///
+/// # Notes
+///
+/// If the two vectors passed to `reverse_get` (or set through the iso) differ in length, the
+/// longer one is truncated to the length of the shorter one. This cannot happen with vectors
+/// obtained through `get`, which always have equal lengths.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalReverseGet, unzip_iso};
+///
+/// let unzip = unzip_iso::<char, u8>();
+///
+/// assert_eq!(unzip.get(&vec![('a', 1), ('b', 2)]), (vec!['a', 'b'], vec![1, 2]));
+/// assert_eq!(unzip.reverse_get(&(vec!['a', 'b'], vec![1])), vec![('a', 1)]);
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<A: Clone, B: Clone>()
+-> IsoImpl<Vec<(A, B)>, (Vec<A>, Vec<B>), impl Iso<Vec<(A, B)>, (Vec<A>, Vec<B>)>> {
+    mapped_iso(
+        |source: &Vec<(A, B)>| source.iter().cloned().unzip(),
+        |(left, right): &(Vec<A>, Vec<B>)| {
+            left.iter().cloned().zip(right.iter().cloned()).collect()
+        },
+    )
+}
@@ -1,7 +1,13 @@
+use crate::optics::fold::getter::from_getter;
+use crate::optics::iso::checked::new as checked_iso;
+use crate::optics::iso::reversed::new as reversed_iso;
+use crate::optics::traversal::prism::from_prism;
 use crate::{
-    FallibleIso, FallibleIsoImpl, HasGetter, HasReverseGet, HasSetter, HasTotalGetter,
-    HasTotalReverseGet, Iso, Lens, LensImpl, Prism, PrismImpl, composed_fallible_iso, composed_iso,
-    composed_lens, composed_prism, infallible,
+    FallibleIso, FallibleIsoImpl, Fold, FoldImpl, Getter, GetterImpl, HasGetter, HasReverseGet,
+    HasSetter, HasTotalGetter, HasTotalReverseGet, Iso, Lens, LensImpl, PartialGetter,
+    PartialGetterImpl, Prism, PrismImpl, Traversal, TraversalImpl, composed_fallible_iso,
+    composed_fold, composed_getter, composed_iso, composed_lens, composed_partial_getter,
+    composed_prism, composed_traversal, infallible, mapped_iso,
 };
 use core::convert::{Infallible, identity};
 use core::marker::PhantomData;
@@ -43,6 +49,52 @@ impl<S, A, ISO: Iso<S, A>> HasReverseGet<S, A> for IsoImpl<S, A, ISO> {
     }
 }
 
+impl<S, A, ISO: Iso<S, A>> IsoImpl<S, A, ISO> {
+    /// Wraps this iso so that every `get` verifies the round-trip law `reverse_get(get(s)) == s`.
+    ///
+    /// The check is only performed when `debug_assertions` are enabled or the `law-checks`
+    /// feature is active, so it is free in regular release builds.
+    ///
+    /// # Panics
+    ///
+    /// The returned iso panics on `get` if the round-trip law does not hold for the source.
+    #[must_use]
+    pub fn checked(self) -> IsoImpl<S, A, impl Iso<S, A>>
+    where
+        S: PartialEq,
+    {
+        checked_iso(self.0)
+    }
+
+    /// Post-composes the bijection given by `f` and its inverse `g` onto the focus of this iso,
+    /// resulting in an `IsoImpl<S, B>`.
+    ///
+    /// Reading applies `f` after this iso's `get`, and reversing applies `g` before this iso's
+    /// `reverse_get`. This is a shorthand for composing with `mapped_iso(f, g)` through
+    /// [`compose_with_iso`](IsoImpl::compose_with_iso). `f` and `g` must be inverses of each
+    /// other for the result to be a lawful iso.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, HasTotalReverseGet, mapped_iso};
+    ///
+    /// let to_cents = mapped_iso(|c: &u32| c * 100, |e| e / 100);
+    /// let to_half_cents = to_cents.bimap(|c| c * 2, |h| h / 2);
+    ///
+    /// assert_eq!(to_half_cents.get(&3), 600);
+    /// assert_eq!(to_half_cents.reverse_get(&600), 3);
+    /// ```
+    #[must_use]
+    pub fn bimap<B, F, G>(self, f: F, g: G) -> IsoImpl<S, B, impl Iso<S, B>>
+    where
+        F: Fn(&A) -> B,
+        G: Fn(&B) -> A,
+    {
+        self.compose_with_iso(mapped_iso(f, g))
+    }
+}
+
 impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
@@ -51,6 +103,20 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
         composed_lens(self, other)
     }
 
+    pub fn compose_with_getter<A, G2: Getter<I, A>>(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> GetterImpl<S, A, impl Getter<S, A>> {
+        composed_getter(self.0, other.0)
+    }
+
+    pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        composed_partial_getter(self.0, other.0, infallible, identity)
+    }
+
     pub fn compose_with_prism<A, P2: Prism<I, A>>(
         self,
         other: P2,
@@ -75,4 +141,48 @@ impl<S, I, ISO1: Iso<S, I>> IsoImpl<S, I, ISO1> {
     ) -> IsoImpl<S, A, impl Iso<S, A>> {
         composed_iso(self.0, other.0)
     }
+
+    /// Composes this `IsoImpl<S, I>` with an `Iso<A, I>` run backwards, resulting in an
+    /// `IsoImpl<S, A>`.
+    ///
+    /// Reading applies the `reverse_get` of `other` after the `get` of `self`, and reversing
+    /// applies the `get` of `other` before the `reverse_get` of `self`. This suits pipelines where
+    /// `other` is more naturally defined in the opposite direction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, HasTotalReverseGet, mapped_iso};
+    ///
+    /// let cents = mapped_iso(|c: &(u64,)| c.0, |c| (*c,));
+    /// let dollars_to_cents = mapped_iso(|d: &u64| d * 100, |c| c / 100);
+    /// let dollars = cents.then_reverse(dollars_to_cents);
+    ///
+    /// assert_eq!(dollars.get(&(1200,)), 12);
+    /// assert_eq!(dollars.reverse_get(&3), (300,));
+    /// ```
+    pub fn then_reverse<A, ISO2: Iso<A, I>>(
+        self,
+        other: IsoImpl<A, I, ISO2>,
+    ) -> IsoImpl<S, A, impl Iso<S, A>> {
+        composed_iso(self.0, reversed_iso(other.0).0)
+    }
+
+    /// Composes this `IsoImpl<S, I>` with a `Traversal<I, A>`, resulting in a `TraversalImpl<S, A>`
+    /// focusing on every focus value of `other` within the focus of `self`.
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: TraversalImpl<I, A, T2>,
+    ) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        composed_traversal(from_prism(self.0), other.0)
+    }
+
+    /// Composes this `IsoImpl<S, I>` with a `Fold<I, A>`, resulting in a read-only
+    /// `FoldImpl<S, A>` focusing on every focus value of `other` within the focus of `self`.
+    pub fn compose_with_fold<A, F2: Fold<I, A>>(
+        self,
+        other: FoldImpl<I, A, F2>,
+    ) -> FoldImpl<S, A, impl Fold<S, A>> {
+        composed_fold(from_getter(self.0), other.0)
+    }
 }
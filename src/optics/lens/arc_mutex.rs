@@ -0,0 +1,48 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Creates a `Lens` focusing on the value shared behind an `Arc<Mutex<T>>`.
+///
+/// Reading locks the mutex, clones the value and unlocks it. Setting locks the mutex, overwrites
+/// the value and unlocks it. Both only need shared access to the mutex, so a write through one
+/// handle of the `Arc` is seen by every other handle to the same allocation.
+///
+/// # Deadlocks
+///
+/// The lock is held for the whole `get` or `set`, and `Mutex` is not reentrant. Composed optics
+/// must not lock the same mutex again while it is held, e.g. from inside a closure this lens
+/// calls, or the thread deadlocks or panics.
+///
+/// # Poisoning
+///
+/// A mutex poisoned by a thread that panicked while holding it is treated as unpoisoned: reading
+/// returns the value as it was left, and setting overwrites it. The optic itself never panics on
+/// poisoning.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, arc_mutex_lens};
+/// use std::sync::{Arc, Mutex};
+///
+/// let shared = arc_mutex_lens();
+/// let mut counter = Arc::new(Mutex::new(1));
+/// let observer = Arc::clone(&counter);
+///
+/// shared.set(&mut counter, 2);
+/// assert_eq!(shared.get(&observer), 2);
+/// ```
+#[must_use]
+pub fn new<T: Clone>() -> LensImpl<Arc<Mutex<T>>, T, impl Lens<Arc<Mutex<T>>, T>> {
+    mapped_lens(
+        |source: &Arc<Mutex<T>>| {
+            source
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner)
+                .clone()
+        },
+        |source: &mut Arc<Mutex<T>>, value| {
+            *source.lock().unwrap_or_else(PoisonError::into_inner) = value;
+        },
+    )
+}
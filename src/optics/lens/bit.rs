@@ -0,0 +1,39 @@
+use crate::{Lens, LensImpl, mapped_lens};
+
+/// Creates a `Lens` focusing on bit `n` of a `u32`, counting from the least significant bit.
+///
+/// Reading tests whether the bit is set. Setting `true` sets the bit and setting `false` clears
+/// it, leaving all other bits unchanged.
+///
+/// # Panics
+///
+/// Panics if `n >= 32`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, bit_lens};
+///
+/// let executable = bit_lens(0);
+/// let mut mode = 0o644;
+///
+/// assert!(!executable.get(&mode));
+///
+/// executable.set(&mut mode, true);
+/// assert_eq!(mode, 0o645);
+/// ```
+#[must_use]
+pub fn new(n: u32) -> LensImpl<u32, bool, impl Lens<u32, bool>> {
+    assert!(n < u32::BITS, "bit_lens requires n < 32");
+    let mask = 1 << n;
+    mapped_lens(
+        move |source: &u32| source & mask != 0,
+        move |source, value| {
+            if value {
+                *source |= mask;
+            } else {
+                *source &= !mask;
+            }
+        },
+    )
+}
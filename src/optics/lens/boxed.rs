@@ -0,0 +1,69 @@
+use crate::{HasGetter, HasRefGetter, HasSetter, Lens, LensImpl};
+use alloc::boxed::Box;
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// A `Lens` focusing on the value inside a `Box`.
+struct BoxDeref<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Clone> HasGetter<Box<T>, T> for BoxDeref<T> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &Box<T>) -> Result<T, Self::GetterError> {
+        Ok(T::clone(source))
+    }
+}
+
+impl<T: Clone> HasSetter<Box<T>, T> for BoxDeref<T> {
+    fn set(&self, source: &mut Box<T>, value: T) {
+        **source = value;
+    }
+}
+
+impl<T: Clone> HasRefGetter<Box<T>, T> for BoxDeref<T> {
+    type RefGetterError = Infallible;
+
+    fn try_get_ref<'a>(&self, source: &'a Box<T>) -> Result<&'a T, Self::RefGetterError> {
+        Ok(source)
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut Box<T>) -> Result<&'a mut T, Self::RefGetterError> {
+        Ok(source)
+    }
+}
+
+/// Creates a `Lens` focusing on the value inside a `Box`.
+///
+/// This allows lensing into recursive boxed structures, like linked lists or trees. Setting a
+/// value writes it into the existing allocation of the box.
+///
+/// # Notes
+///
+/// - Reading through the owned path, `get`, clones the boxed value, hence `T: Clone`. To avoid
+///   cloning, borrow the value instead through [`HasRefGetter`], or its infallible counterpart
+///   [`HasTotalRefGetter`], which the returned lens also implements.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalRefGetter, box_deref_lens};
+///
+/// let boxed = Box::new(7);
+///
+/// assert_eq!(box_deref_lens().get(&boxed), 7);
+/// assert_eq!(box_deref_lens().get_ref(&boxed), &7);
+/// ```
+///
+/// [`HasTotalRefGetter`]: crate::HasTotalRefGetter
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: Clone>()
+-> LensImpl<Box<T>, T, impl Lens<Box<T>, T> + HasRefGetter<Box<T>, T, RefGetterError = Infallible>>
+{
+    BoxDeref {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,29 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use core::cell::Cell;
+
+/// Creates a `Lens` focusing on the value held by a [`Cell`].
+///
+/// Reading copies the value out with [`Cell::get`], and setting replaces it with [`Cell::set`].
+/// Since `T` is `Copy`, neither can panic, unlike borrowing a `RefCell`. This makes it easy to
+/// reach `Cell`-held counters and flags nested inside other structures.
+///
+/// Setting goes through [`HasSetter::set`], which takes the source by mutable reference like
+/// for every other optic. Code holding only a shared `&Cell<T>` can still read through the lens.
+///
+/// [`HasSetter::set`]: crate::HasSetter::set
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, cell_lens};
+/// use std::cell::Cell;
+///
+/// let mut hits = Cell::new(1);
+///
+/// cell_lens().set(&mut hits, 2);
+/// assert_eq!(cell_lens().get(&hits), 2);
+/// ```
+#[must_use]
+pub fn new<T: Copy>() -> LensImpl<Cell<T>, T, impl Lens<Cell<T>, T>> {
+    mapped_lens(Cell::get, |source: &mut Cell<T>, value| source.set(value))
+}
@@ -0,0 +1,69 @@
+use crate::{HasGetter, HasSetter, Lens, LensImpl};
+use core::marker::PhantomData;
+
+/// A `Lens` wrapper clamping every value written through it into `[min, max]`.
+struct ClampedLens<S, A, L: Lens<S, A>> {
+    lens: L,
+    min: A,
+    max: A,
+    phantom: PhantomData<S>,
+}
+
+impl<S, A: Ord + Clone, L: Lens<S, A>> HasGetter<S, A> for ClampedLens<S, A, L> {
+    type GetterError = L::GetterError;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.lens.try_get(source)
+    }
+}
+
+impl<S, A: Ord + Clone, L: Lens<S, A>> HasSetter<S, A> for ClampedLens<S, A, L> {
+    fn set(&self, source: &mut S, value: A) {
+        self.lens
+            .set(source, value.clamp(self.min.clone(), self.max.clone()));
+    }
+}
+
+/// Creates a `Lens` that clamps every value written through `lens` into `[min, max]`.
+///
+/// Reading returns the stored value unchanged, even if it lies outside the range because it was
+/// written by other means. This enforces range invariants at the write boundary, e.g. for
+/// sliders or volume controls.
+///
+/// # Arguments
+///
+/// - `lens` — The lens whose writes are clamped.
+/// - `min` — The smallest value that can be written.
+/// - `max` — The largest value that can be written.
+///
+/// # Panics
+///
+/// Panics if `min > max`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, clamped_lens, mapped_lens};
+///
+/// let volume = clamped_lens(mapped_lens(|v: &u8| *v, |v, x| *v = x), 0, 10);
+///
+/// let mut level = 5;
+/// volume.set(&mut level, 42);
+///
+/// assert_eq!(volume.get(&level), 10);
+/// ```
+#[must_use]
+pub fn new<S, A: Ord + Clone, L: Lens<S, A>>(
+    lens: L,
+    min: A,
+    max: A,
+) -> LensImpl<S, A, impl Lens<S, A>> {
+    assert!(min <= max, "clamped_lens requires min <= max");
+    ClampedLens {
+        lens,
+        min,
+        max,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,32 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use alloc::vec::Vec;
+
+/// Creates a `Lens` focusing a `Vec` that stores its values with consecutive duplicates removed.
+///
+/// Reading returns a clone of the vector as it is stored. Setting stores the given vector with
+/// runs of equal adjacent elements collapsed as by [`Vec::dedup`], keeping the first element of
+/// each run, so a sorted vector ends up without any duplicates.
+///
+/// Because reading returns the current contents, modifying through this lens (e.g. with
+/// [`HasOver::over`](crate::HasOver::over)) dedups the stored vector in place.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasOver, HasSetter, dedup_adjacent_lens};
+///
+/// let mut tags = vec![];
+/// dedup_adjacent_lens().set(&mut tags, vec!["a", "a", "b", "a"]);
+/// assert_eq!(tags, vec!["a", "b", "a"]);
+///
+/// let mut sorted = vec![1, 1, 2, 3, 3];
+/// dedup_adjacent_lens().over(&mut sorted, |v| v);
+/// assert_eq!(sorted, vec![1, 2, 3]);
+/// ```
+#[must_use]
+pub fn new<A: PartialEq + Clone>() -> LensImpl<Vec<A>, Vec<A>, impl Lens<Vec<A>, Vec<A>>> {
+    mapped_lens(Vec::clone, |vec: &mut Vec<A>, mut value: Vec<A>| {
+        value.dedup();
+        *vec = value;
+    })
+}
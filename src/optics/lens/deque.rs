@@ -0,0 +1,78 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use alloc::collections::VecDeque;
+
+/// Creates a `Lens` focusing on the front element of a `VecDeque`.
+///
+/// Reading peeks at the front element, or `None` if the deque is empty. Setting `Some(a)`
+/// replaces the front element, or pushes `a` if the deque is empty, and setting `None` pops the
+/// front element. All operations are O(1).
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, deque_front_lens};
+/// use std::collections::VecDeque;
+///
+/// let front = deque_front_lens();
+/// let mut queue = VecDeque::from([1, 2, 3]);
+///
+/// assert_eq!(front.get(&queue), Some(1));
+///
+/// front.set(&mut queue, None);
+/// assert_eq!(queue, [2, 3]);
+///
+/// front.set(&mut queue, Some(9));
+/// assert_eq!(queue, [9, 3]);
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn front<A: Clone>() -> LensImpl<VecDeque<A>, Option<A>, impl Lens<VecDeque<A>, Option<A>>> {
+    mapped_lens(
+        |deque: &VecDeque<A>| deque.front().cloned(),
+        |deque, value| match (value, deque.front_mut()) {
+            (Some(value), Some(front)) => *front = value,
+            (Some(value), None) => deque.push_front(value),
+            (None, _) => {
+                deque.pop_front();
+            }
+        },
+    )
+}
+
+/// Creates a `Lens` focusing on the back element of a `VecDeque`.
+///
+/// Reading peeks at the back element, or `None` if the deque is empty. Setting `Some(a)`
+/// replaces the back element, or pushes `a` if the deque is empty, and setting `None` pops the
+/// back element. All operations are O(1).
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, deque_back_lens};
+/// use std::collections::VecDeque;
+///
+/// let back = deque_back_lens();
+/// let mut queue = VecDeque::from([1, 2, 3]);
+///
+/// assert_eq!(back.get(&queue), Some(3));
+///
+/// back.set(&mut queue, None);
+/// assert_eq!(queue, [1, 2]);
+///
+/// back.set(&mut queue, Some(9));
+/// assert_eq!(queue, [1, 9]);
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn back<A: Clone>() -> LensImpl<VecDeque<A>, Option<A>, impl Lens<VecDeque<A>, Option<A>>> {
+    mapped_lens(
+        |deque: &VecDeque<A>| deque.back().cloned(),
+        |deque, value| match (value, deque.back_mut()) {
+            (Some(value), Some(back)) => *back = value,
+            (Some(value), None) => deque.push_back(value),
+            (None, _) => {
+                deque.pop_back();
+            }
+        },
+    )
+}
@@ -0,0 +1,63 @@
+use crate::{HasGetter, HasSetter, Lens, LensImpl};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+use core::ops::{Deref, DerefMut};
+
+/// A `Lens` over a smart pointer, reading and writing through another lens on its target.
+struct DerefLift<S, T, A, L: Lens<T, A>> {
+    lens: L,
+    phantom: PhantomData<(S, T, A)>,
+}
+
+impl<S: Deref<Target = T>, T, A, L: Lens<T, A>> HasGetter<S, A> for DerefLift<S, T, A, L> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.lens.try_get(source)
+    }
+}
+
+impl<S: DerefMut<Target = T>, T, A, L: Lens<T, A>> HasSetter<S, A> for DerefLift<S, T, A, L> {
+    fn set(&self, source: &mut S, value: A) {
+        self.lens.set(source, value);
+    }
+}
+
+/// Lifts a `Lens<T, A>` to a `Lens<S, A>` over any pointer `S` dereferencing to `T`, such as
+/// `Box<T>`.
+///
+/// Reading goes through [`Deref::deref`] and setting through [`DerefMut::deref_mut`], so the
+/// inner lens works on the pointed-to value in place, and the pointer itself is never cloned or
+/// replaced.
+///
+/// # Notes
+///
+/// - Setting needs mutable access to the target, so `S` must implement [`DerefMut`], not just
+///   [`Deref`]. Shared pointers like `Rc` and `Arc` only implement `Deref`, so use
+///   [`deref_lift_getter`] to read through them.
+///
+/// [`deref_lift_getter`]: crate::deref_lift_getter
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, deref_lift, field_lens};
+///
+/// struct Node { value: i32 }
+///
+/// let value = deref_lift(field_lens(|n: &Node| &n.value, |n: &mut Node| &mut n.value));
+/// let mut node = Box::new(Node { value: 1 });
+///
+/// value.set(&mut node, 2);
+/// assert_eq!(value.get(&node), 2);
+/// ```
+#[must_use]
+pub fn new<S: DerefMut<Target = T>, T, A, L: Lens<T, A>>(
+    lens: LensImpl<T, A, L>,
+) -> LensImpl<S, A, impl Lens<S, A>> {
+    DerefLift {
+        lens: lens.0,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,82 @@
+use crate::{HasGetter, HasRefGetter, HasSetter, Lens, LensImpl};
+use core::convert::Infallible;
+
+/// A `Lens` focusing on a struct field through a pair of plain function pointers.
+struct FieldLens<S, A> {
+    get: fn(&S) -> &A,
+    get_mut: fn(&mut S) -> &mut A,
+}
+
+impl<S, A: Clone> HasGetter<S, A> for FieldLens<S, A> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        Ok((self.get)(source).clone())
+    }
+}
+
+impl<S, A: Clone> HasSetter<S, A> for FieldLens<S, A> {
+    fn set(&self, source: &mut S, value: A) {
+        *(self.get_mut)(source) = value;
+    }
+}
+
+impl<S, A: Clone> HasRefGetter<S, A> for FieldLens<S, A> {
+    type RefGetterError = Infallible;
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        Ok((self.get)(source))
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        Ok((self.get_mut)(source))
+    }
+}
+
+/// Creates a `Lens` focusing on a field of `S` from a pair of function pointers returning
+/// references to it.
+///
+/// Unlike [`mapped_lens`], no closures are captured and nothing is cloned on `set`: the new value
+/// is written in place through `get_mut`. The returned lens also implements [`HasRefGetter`], so
+/// the field can be borrowed without cloning it.
+///
+/// # Parameters
+///
+/// - `get`: Borrows the field from the source.
+/// - `get_mut`: Mutably borrows the field from the source.
+///
+/// # Notes
+///
+/// - Reading through the owned path, `get`, still clones the field, hence `A: Clone`. Use
+///   [`HasTotalRefGetter::get_ref`] to read it by reference instead.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, HasTotalRefGetter, HasSetter, field_lens};
+///
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// let x = field_lens(|p: &Point| &p.x, |p: &mut Point| &mut p.x);
+/// let mut point = Point { x: 1, y: 2 };
+///
+/// x.set(&mut point, 5);
+///
+/// assert_eq!(x.get(&point), 5);
+/// assert_eq!(x.get_ref(&point), &5);
+/// assert_eq!(point.y, 2);
+/// ```
+///
+/// [`mapped_lens`]: crate::mapped_lens
+/// [`HasTotalRefGetter::get_ref`]: crate::HasTotalRefGetter::get_ref
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<S, A: Clone>(
+    get: fn(&S) -> &A,
+    get_mut: fn(&mut S) -> &mut A,
+) -> LensImpl<S, A, impl Lens<S, A> + HasRefGetter<S, A, RefGetterError = Infallible>> {
+    FieldLens { get, get_mut }.into()
+}
@@ -2,12 +2,59 @@ use crate::HasGetter;
 use crate::HasSetter;
 use core::convert::Infallible;
 
+#[cfg(feature = "std")]
+mod arc_mutex;
+mod bit;
+#[cfg(feature = "alloc")]
+mod boxed;
+mod cell;
+mod clamped;
 mod composed;
+#[cfg(feature = "alloc")]
+mod dedup;
+#[cfg(feature = "alloc")]
+mod deque;
+mod deref;
+mod field;
 mod mapped;
+mod pair_split;
+mod read_write;
+mod ref_composed;
+mod result;
+#[cfg(feature = "alloc")]
+mod sorted_view;
+#[cfg(feature = "alloc")]
+mod split_at;
+mod tuple;
+#[cfg(feature = "alloc")]
+mod vec_len;
 mod wrapper;
 
+#[cfg(feature = "std")]
+pub use arc_mutex::new as arc_mutex_lens;
+pub use bit::new as bit_lens;
+#[cfg(feature = "alloc")]
+pub use boxed::new as box_deref_lens;
+pub use cell::new as cell_lens;
+pub use clamped::new as clamped_lens;
 pub use composed::new as composed_lens;
+#[cfg(feature = "alloc")]
+pub use dedup::new as dedup_adjacent_lens;
+#[cfg(feature = "alloc")]
+pub use deque::{back as deque_back_lens, front as deque_front_lens};
+pub use deref::new as deref_lift;
+pub use field::new as field_lens;
 pub use mapped::new as mapped_lens;
+pub use pair_split::new as pair_split_lens;
+pub use read_write::new as read_write;
+pub use result::{keep_err as ok_lens, new as result_ok_lens};
+#[cfg(feature = "alloc")]
+pub use sorted_view::new as sorted_view_lens;
+#[cfg(feature = "alloc")]
+pub use split_at::{new as split_at_lens, string as split_at_string_lens};
+pub use tuple::{TupleLens, first as tuple_0, nth as tuple_lens, second as tuple_1};
+#[cfg(feature = "alloc")]
+pub use vec_len::new as vec_len_lens;
 pub use wrapper::LensImpl;
 
 /// An optic for focusing on a value that is guaranteed to exist within a larger structure.
@@ -24,7 +71,28 @@ pub use wrapper::LensImpl;
 /// - [`Prism`] — optional focus optic for sum types
 /// - [`Iso`] — reversible transformations
 /// - [`FallibleIso`] — reversible transformations with fallible forward mapping
-pub trait Lens<S, A>: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> {}
+pub trait Lens<S, A>: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A> {
+    /// Constructs a source from a focus value, using `S::default()` for everything else.
+    ///
+    /// This sets `value` through the lens on a default source, which is useful for building
+    /// records from a single known field.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: The focus value to build the source from.
+    ///
+    /// # Returns
+    ///
+    /// A default source of type `S` with its focus set to `value`.
+    fn build_with_default(&self, value: A) -> S
+    where
+        S: Default,
+    {
+        let mut source = S::default();
+        self.set(&mut source, value);
+        source
+    }
+}
 
 impl<S, A, L: HasGetter<S, A, GetterError = Infallible> + HasSetter<S, A>> Lens<S, A> for L {}
 
@@ -0,0 +1,45 @@
+use crate::{Lens, LensImpl, mapped_lens};
+
+/// Creates a `Lens` viewing a source as the pair of its two parts.
+///
+/// Reading calls `get_a` and `get_b` to extract the parts, and setting rebuilds the whole source
+/// from the new pair with `build`, replacing the previous value. This does not require `S` to be
+/// `Clone`, only to be constructible from its two parts.
+///
+/// # Arguments
+///
+/// - `get_a` — A function reading the first part from the source.
+/// - `get_b` — A function reading the second part from the source.
+/// - `build` — A function constructing a source from both parts.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, pair_split_lens};
+///
+/// struct Complex { re: f64, im: f64 }
+///
+/// let parts = pair_split_lens(|c: &Complex| c.re, |c: &Complex| c.im, |re, im| Complex { re, im });
+///
+/// let mut z = Complex { re: 1.0, im: 2.0 };
+/// assert_eq!(parts.get(&z), (1.0, 2.0));
+///
+/// parts.set(&mut z, (3.0, -1.0));
+/// assert_eq!((z.re, z.im), (3.0, -1.0));
+/// ```
+#[must_use]
+pub fn new<S, A, B, GA, GB, BUILD>(
+    get_a: GA,
+    get_b: GB,
+    build: BUILD,
+) -> LensImpl<S, (A, B), impl Lens<S, (A, B)>>
+where
+    GA: Fn(&S) -> A,
+    GB: Fn(&S) -> B,
+    BUILD: Fn(A, B) -> S,
+{
+    mapped_lens(
+        move |source: &S| (get_a(source), get_b(source)),
+        move |source, (a, b)| *source = build(a, b),
+    )
+}
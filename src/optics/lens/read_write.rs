@@ -0,0 +1,70 @@
+use crate::{Getter, GetterImpl, HasGetter, HasSetter, Lens, LensImpl, Setter, SetterImpl};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// A `Lens` built from an unrelated `Getter` and `Setter` targeting the same focus.
+struct ReadWriteLens<S, A, G: Getter<S, A>, SETTER: Setter<S, A>> {
+    getter: G,
+    setter: SETTER,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, G: Getter<S, A>, SETTER: Setter<S, A>> HasGetter<S, A>
+    for ReadWriteLens<S, A, G, SETTER>
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.getter.try_get(source)
+    }
+}
+
+impl<S, A, G: Getter<S, A>, SETTER: Setter<S, A>> HasSetter<S, A>
+    for ReadWriteLens<S, A, G, SETTER>
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.setter.set(source, value);
+    }
+}
+
+/// Creates a `Lens` reading through `getter` and writing through `setter`.
+///
+/// This is for the occasional case where the read and write paths of a focus differ, e.g. a
+/// computed getter paired with a validating setter. The two optics are not checked against each
+/// other: the caller is responsible for them agreeing on the focus, so that the resulting lens
+/// obeys the lens laws. In particular, reading right after setting a value should return that
+/// value, and setting the value just read should leave the source unchanged.
+///
+/// # Arguments
+///
+/// - `getter` — The getter reading the focus value.
+/// - `setter` — The setter writing the focus value.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, mapped_getter, mapped_setter, read_write};
+///
+/// struct Temperature { kelvin: f64 }
+///
+/// let celsius = read_write(
+///     mapped_getter(|t: &Temperature| t.kelvin - 273.15),
+///     mapped_setter(|t: &mut Temperature, c: f64| t.kelvin = c.max(-273.15) + 273.15),
+/// );
+///
+/// let mut t = Temperature { kelvin: 300.0 };
+/// celsius.set(&mut t, 20.0);
+/// assert!((celsius.get(&t) - 20.0).abs() < 1e-9);
+/// ```
+#[must_use]
+pub fn new<S, A, G: Getter<S, A>, SETTER: Setter<S, A>>(
+    getter: GetterImpl<S, A, G>,
+    setter: SetterImpl<S, A, SETTER>,
+) -> LensImpl<S, A, impl Lens<S, A>> {
+    ReadWriteLens {
+        getter: getter.0,
+        setter: setter.0,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,75 @@
+use crate::optics::lens::Lens;
+use crate::{HasGetter, HasRefGetter, HasSetter, HasTotalRefGetter, LensImpl};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// A composed `Lens` borrowing through both of its lenses, instead of cloning the intermediate
+/// focus value.
+///
+/// This struct is automatically created by [`LensImpl::compose_with_ref_lens`], and cannot be
+/// constructed manually.
+struct RefComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasRefGetter<S, I, RefGetterError = Infallible>,
+    L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+{
+    optic1: L1,
+    optic2: L2,
+    _phantom: PhantomData<(S, I, A)>,
+}
+
+impl<S, I, A, L1, L2> HasGetter<S, A> for RefComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasRefGetter<S, I, RefGetterError = Infallible>,
+    L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.optic2.try_get(self.optic1.get_ref(source))
+    }
+}
+
+impl<S, I, A, L1, L2> HasSetter<S, A> for RefComposedLens<L1, L2, S, I, A>
+where
+    L1: Lens<S, I> + HasRefGetter<S, I, RefGetterError = Infallible>,
+    L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.optic2.set(self.optic1.get_mut(source), value);
+    }
+}
+
+impl<S, I, A, L1, L2> HasRefGetter<S, A> for RefComposedLens<L1, L2, S, I, A>
+where
+    I: 'static,
+    L1: Lens<S, I> + HasRefGetter<S, I, RefGetterError = Infallible>,
+    L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+{
+    type RefGetterError = Infallible;
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        self.optic2.try_get_ref(self.optic1.get_ref(source))
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        self.optic2.try_get_mut(self.optic1.get_mut(source))
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new<S, A, I: 'static, L1, L2>(
+    l1: L1,
+    l2: L2,
+) -> LensImpl<S, A, impl Lens<S, A> + HasRefGetter<S, A, RefGetterError = Infallible>>
+where
+    L1: Lens<S, I> + HasRefGetter<S, I, RefGetterError = Infallible>,
+    L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+{
+    RefComposedLens {
+        optic1: l1,
+        optic2: l2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,86 @@
+use crate::{Lens, LensImpl, mapped_lens};
+
+/// Creates a `Lens` viewing a `Result<T, E>` as an `Option<T>`.
+///
+/// Reading maps `Ok(t)` to `Some(t)` and any `Err` to `None`. Setting `Some(t)` stores `Ok(t)`,
+/// while setting `None` stores `Err(default_err())`.
+///
+/// # Arguments
+///
+/// - `default_err` — A function producing the error stored when `None` is set.
+///
+/// # Notes
+///
+/// - This cannot be an iso: every `Err(e)` reads as `None`, so the original error is lost and
+///   `None` can only be turned back into the error built by `default_err`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, result_ok_lens};
+///
+/// let ok = result_ok_lens(|| "missing");
+///
+/// let mut value: Result<u32, &str> = Err("boom");
+/// assert_eq!(ok.get(&value), None);
+///
+/// ok.set(&mut value, Some(3));
+/// assert_eq!(value, Ok(3));
+///
+/// ok.set(&mut value, None);
+/// assert_eq!(value, Err("missing"));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: Clone, E, ERR>(
+    default_err: ERR,
+) -> LensImpl<Result<T, E>, Option<T>, impl Lens<Result<T, E>, Option<T>>>
+where
+    ERR: Fn() -> E,
+{
+    mapped_lens(
+        |source: &Result<T, E>| source.as_ref().ok().cloned(),
+        move |source, value: Option<T>| *source = value.ok_or_else(&default_err),
+    )
+}
+
+/// Creates a `Lens` viewing a `Result<T, E>` as an `Option<T>`, keeping any error aside.
+///
+/// Reading maps `Ok(t)` to `Some(t)` and any `Err` to `None`, like [`result_ok_lens`]. Setting
+/// `Some(t)` stores `Ok(t)`, replacing an error if there was one. Setting `None` never changes
+/// the source: an `Err` keeps its original payload, and an `Ok` keeps its value.
+///
+/// # Notes
+///
+/// - Since setting `None` on an `Ok` is a no-op, reading back afterwards still gives `Some`.
+///   The lens only obeys the set-get law for `Some` values, and for `None` on an `Err` source.
+///
+/// [`result_ok_lens`]: crate::result_ok_lens
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, ok_lens};
+///
+/// let ok = ok_lens();
+///
+/// let mut value: Result<u32, &str> = Err("boom");
+/// ok.set(&mut value, None);
+/// assert_eq!(value, Err("boom"));
+///
+/// ok.set(&mut value, Some(3));
+/// assert_eq!(ok.get(&value), Some(3));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn keep_err<T: Clone, E>()
+-> LensImpl<Result<T, E>, Option<T>, impl Lens<Result<T, E>, Option<T>>> {
+    mapped_lens(
+        |source: &Result<T, E>| source.as_ref().ok().cloned(),
+        |source, value: Option<T>| {
+            if let Some(value) = value {
+                *source = Ok(value);
+            }
+        },
+    )
+}
@@ -0,0 +1,36 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use alloc::vec::Vec;
+
+/// Creates a `Lens` viewing a `Vec` in sorted order, while storing it in its original order.
+///
+/// Reading returns a sorted clone of the vector and leaves the source untouched. Setting replaces
+/// the whole vector with exactly the given value, which does not need to be sorted.
+///
+/// This is not an `Iso`: sorting forgets the original order, so it cannot be recovered from the
+/// focus. Reading and writing back the focus therefore sorts the stored vector.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, sorted_view_lens};
+///
+/// let sorted = sorted_view_lens();
+/// let mut arrivals = vec![3, 1, 2];
+///
+/// assert_eq!(sorted.get(&arrivals), [1, 2, 3]);
+/// assert_eq!(arrivals, [3, 1, 2]);
+///
+/// sorted.set(&mut arrivals, vec![5, 4]);
+/// assert_eq!(arrivals, [5, 4]);
+/// ```
+#[must_use]
+pub fn new<A: Ord + Clone>() -> LensImpl<Vec<A>, Vec<A>, impl Lens<Vec<A>, Vec<A>>> {
+    mapped_lens(
+        |vec: &Vec<A>| {
+            let mut sorted = vec.clone();
+            sorted.sort();
+            sorted
+        },
+        |vec: &mut Vec<A>, value| *vec = value,
+    )
+}
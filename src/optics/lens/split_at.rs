@@ -0,0 +1,83 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Creates a `Lens` viewing a `Vec` as the pair of its two halves, split at index `i`.
+///
+/// Reading yields the elements before `i` and the elements from `i` onwards. If `i` is greater
+/// than the length of the vector, the split happens at its end.
+///
+/// # Round-trip semantics
+///
+/// Setting a pair ignores `i` entirely, and simply replaces the vector with the concatenation of
+/// the two halves. Setting what was read is therefore a no-op, but reading after setting a pair
+/// whose first half is not `i` elements long will split at a different place than the pair was
+/// set with.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, split_at_lens};
+///
+/// let halves = split_at_lens(1);
+/// let mut values = vec![1, 2, 3];
+///
+/// assert_eq!(halves.get(&values), (vec![1], vec![2, 3]));
+///
+/// halves.set(&mut values, (vec![1, 2], vec![3, 4]));
+/// assert_eq!(values, vec![1, 2, 3, 4]);
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<A: Clone>(
+    i: usize,
+) -> LensImpl<Vec<A>, (Vec<A>, Vec<A>), impl Lens<Vec<A>, (Vec<A>, Vec<A>)>> {
+    mapped_lens(
+        move |source: &Vec<A>| {
+            let (left, right) = source.split_at(i.min(source.len()));
+            (left.to_vec(), right.to_vec())
+        },
+        |source: &mut Vec<A>, (mut left, mut right): (Vec<A>, Vec<A>)| {
+            left.append(&mut right);
+            *source = left;
+        },
+    )
+}
+
+/// Creates a `Lens` viewing a `String` as the pair of its two halves, split at byte index `i`.
+///
+/// If `i` does not fall on a char boundary, the split happens at the closest char boundary
+/// before `i`, so reading never panics. If `i` is greater than the length of the string, the
+/// split happens at its end.
+///
+/// Setting a pair replaces the string with the concatenation of the two halves, with the same
+/// round-trip semantics as [`split_at_lens`].
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, split_at_string_lens};
+///
+/// let halves = split_at_string_lens(2);
+///
+/// assert_eq!(halves.get(&"héllo".to_string()), ("h".to_string(), "éllo".to_string()));
+/// ```
+///
+/// [`split_at_lens`]: crate::split_at_lens
+#[must_use]
+pub fn string(i: usize) -> LensImpl<String, (String, String), impl Lens<String, (String, String)>> {
+    mapped_lens(
+        move |source: &String| {
+            let mut at = i.min(source.len());
+            while !source.is_char_boundary(at) {
+                at -= 1;
+            }
+            let (left, right) = source.split_at(at);
+            (String::from(left), String::from(right))
+        },
+        |source: &mut String, (mut left, right): (String, String)| {
+            left.push_str(&right);
+            *source = left;
+        },
+    )
+}
@@ -0,0 +1,231 @@
+use crate::{HasGetter, HasSetter, Lens, LensImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A `Lens` focusing on the first element of a pair.
+struct TupleFirst<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+/// A `Lens` focusing on the second element of a pair.
+struct TupleSecond<A, B> {
+    phantom: PhantomData<(A, B)>,
+}
+
+impl<A: Clone, B> HasGetter<(A, B), A> for TupleFirst<A, B> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &(A, B)) -> Result<A, Self::GetterError> {
+        Ok(source.0.clone())
+    }
+}
+
+impl<A, B> HasSetter<(A, B), A> for TupleFirst<A, B> {
+    fn set(&self, source: &mut (A, B), value: A) {
+        source.0 = value;
+    }
+}
+
+impl<A, B: Clone> HasGetter<(A, B), B> for TupleSecond<A, B> {
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &(A, B)) -> Result<B, Self::GetterError> {
+        Ok(source.1.clone())
+    }
+}
+
+impl<A, B> HasSetter<(A, B), B> for TupleSecond<A, B> {
+    fn set(&self, source: &mut (A, B), value: B) {
+        source.1 = value;
+    }
+}
+
+impl<A, B> PartialEq for TupleFirst<A, B> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<A, B> Eq for TupleFirst<A, B> {}
+
+impl<A, B> fmt::Debug for TupleFirst<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tuple_0")
+    }
+}
+
+impl<A, B> PartialEq for TupleSecond<A, B> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<A, B> Eq for TupleSecond<A, B> {}
+
+impl<A, B> fmt::Debug for TupleSecond<A, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("tuple_1")
+    }
+}
+
+/// Creates a `Lens` focusing on the first element of a pair.
+///
+/// Like the other named optics, the returned lens implements `PartialEq`, `Eq` and `Debug`, a
+/// lens being equal to every other lens returned by this function for the same types.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, tuple_0};
+///
+/// assert_eq!(tuple_0().get(&(1, 'a')), 1);
+/// assert_eq!(tuple_0::<i32, char>(), tuple_0::<i32, char>());
+/// ```
+#[must_use]
+pub fn first<A: Clone, B>() -> LensImpl<(A, B), A, impl Lens<(A, B), A> + Eq + fmt::Debug> {
+    TupleFirst {
+        phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Creates a `Lens` focusing on the second element of a pair.
+///
+/// Like the other named optics, the returned lens implements `PartialEq`, `Eq` and `Debug`, a
+/// lens being equal to every other lens returned by this function for the same types.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasTotalGetter, tuple_1};
+///
+/// assert_eq!(tuple_1().get(&(1, 'a')), 'a');
+/// ```
+#[must_use]
+pub fn second<A, B: Clone>() -> LensImpl<(A, B), B, impl Lens<(A, B), B> + Eq + fmt::Debug> {
+    TupleSecond {
+        phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Access to the `N`-th element of a tuple, used by [`tuple_lens`] to focus on it.
+///
+/// This trait is implemented for all tuples of up to eight elements and every valid index `N`,
+/// so that generic code can name a tuple element by its position, e.g. with a
+/// `T: TupleLens<2>` bound.
+///
+/// [`tuple_lens`]: crate::tuple_lens
+pub trait TupleLens<const N: usize> {
+    /// The type of the `N`-th element.
+    type Field;
+
+    /// Returns a reference to the `N`-th element.
+    fn field(&self) -> &Self::Field;
+
+    /// Returns a mutable reference to the `N`-th element.
+    fn field_mut(&mut self) -> &mut Self::Field;
+}
+
+macro_rules! impl_tuple_lens {
+    ($types:tt; $($index:tt => $field:ident),+) => {
+        $(impl_tuple_lens!(@impl $types; $index => $field);)+
+    };
+    (@impl ($($ty:ident),+); $index:tt => $field:ident) => {
+        impl<$($ty),+> TupleLens<$index> for ($($ty,)+) {
+            type Field = $field;
+
+            fn field(&self) -> &$field {
+                &self.$index
+            }
+
+            fn field_mut(&mut self) -> &mut $field {
+                &mut self.$index
+            }
+        }
+    };
+}
+
+impl_tuple_lens!((A); 0 => A);
+impl_tuple_lens!((A, B); 0 => A, 1 => B);
+impl_tuple_lens!((A, B, C); 0 => A, 1 => B, 2 => C);
+impl_tuple_lens!((A, B, C, D); 0 => A, 1 => B, 2 => C, 3 => D);
+impl_tuple_lens!((A, B, C, D, E); 0 => A, 1 => B, 2 => C, 3 => D, 4 => E);
+impl_tuple_lens!((A, B, C, D, E, F); 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F);
+impl_tuple_lens!((A, B, C, D, E, F, G); 0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G);
+impl_tuple_lens!(
+    (A, B, C, D, E, F, G, H);
+    0 => A, 1 => B, 2 => C, 3 => D, 4 => E, 5 => F, 6 => G, 7 => H
+);
+
+/// A `Lens` focusing on the `N`-th element of a tuple.
+struct TupleNth<const N: usize, T> {
+    phantom: PhantomData<T>,
+}
+
+impl<const N: usize, T: TupleLens<N>> HasGetter<T, T::Field> for TupleNth<N, T>
+where
+    T::Field: Clone,
+{
+    type GetterError = Infallible;
+
+    fn try_get(&self, source: &T) -> Result<T::Field, Self::GetterError> {
+        Ok(source.field().clone())
+    }
+}
+
+impl<const N: usize, T: TupleLens<N>> HasSetter<T, T::Field> for TupleNth<N, T> {
+    fn set(&self, source: &mut T, value: T::Field) {
+        *source.field_mut() = value;
+    }
+}
+
+impl<const N: usize, T> PartialEq for TupleNth<N, T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<const N: usize, T> Eq for TupleNth<N, T> {}
+
+impl<const N: usize, T> fmt::Debug for TupleNth<N, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "tuple_lens::<{N}>")
+    }
+}
+
+/// Creates a `Lens` focusing on the `N`-th element of a tuple.
+///
+/// Unlike [`tuple_0`] and [`tuple_1`], the element is selected by the const parameter `N`, which
+/// makes this lens usable in generic code over any `T: TupleLens<N>`. The tuple type is usually
+/// inferred, so only the index has to be spelled out, as in `tuple_lens::<2, _>()`.
+///
+/// Like the other named optics, the returned lens implements `PartialEq`, `Eq` and `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, tuple_lens};
+///
+/// let mut triple = (1, 'a', "x");
+///
+/// assert_eq!(tuple_lens::<1, _>().get(&triple), 'a');
+///
+/// tuple_lens::<2, _>().set(&mut triple, "y");
+/// assert_eq!(triple, (1, 'a', "y"));
+/// ```
+///
+/// [`tuple_0`]: crate::tuple_0
+/// [`tuple_1`]: crate::tuple_1
+#[must_use]
+pub fn nth<const N: usize, T: TupleLens<N>>()
+-> LensImpl<T, T::Field, impl Lens<T, T::Field> + Eq + fmt::Debug>
+where
+    T::Field: Clone,
+{
+    TupleNth {
+        phantom: PhantomData,
+    }
+    .into()
+}
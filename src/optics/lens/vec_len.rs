@@ -0,0 +1,33 @@
+use crate::{Lens, LensImpl, mapped_lens};
+use alloc::vec::Vec;
+
+/// Creates a `Lens` focusing on the length of a `Vec`.
+///
+/// Reading returns the number of elements. Setting a length resizes the vector: growing pads it
+/// with `A::default()`, shrinking truncates it.
+///
+/// Note that shrinking discards the removed elements, so growing the vector back afterwards
+/// pads it with default values rather than restoring the previous ones.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, HasTotalGetter, vec_len_lens};
+///
+/// let len = vec_len_lens();
+/// let mut buffer = vec![1, 2, 3];
+///
+/// assert_eq!(len.get(&buffer), 3);
+///
+/// len.set(&mut buffer, 5);
+/// assert_eq!(buffer, [1, 2, 3, 0, 0]);
+///
+/// len.set(&mut buffer, 2);
+/// assert_eq!(buffer, [1, 2]);
+/// ```
+#[must_use]
+pub fn new<A: Default>() -> LensImpl<Vec<A>, usize, impl Lens<Vec<A>, usize>> {
+    mapped_lens(Vec::len, |vec: &mut Vec<A>, len| {
+        vec.resize_with(len, A::default);
+    })
+}
@@ -1,8 +1,16 @@
+use crate::optics::fold::getter::from_getter;
+use crate::optics::lens::ref_composed::new as ref_composed_lens;
+use crate::optics::traversal::prism::from_prism;
 use crate::{
-    FallibleIso, FallibleIsoImpl, HasGetter, HasSetter, HasTotalGetter, Iso, IsoImpl, Lens, Prism,
-    PrismImpl, composed_lens, composed_prism, infallible,
+    FallibleIso, FallibleIsoImpl, Fold, FoldImpl, Getter, GetterImpl, HasGetter, HasRefGetter,
+    HasSetter, HasTotalGetter, Iso, IsoImpl, Lens, PartialGetter, PartialGetterImpl, Prism,
+    PrismImpl, Traversal, TraversalImpl, composed_fold, composed_getter, composed_lens,
+    composed_partial_getter, composed_prism, composed_traversal, infallible,
 };
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::convert::{Infallible, identity};
+use core::fmt;
 use core::marker::PhantomData;
 
 pub struct LensImpl<S, A, L: Lens<S, A>>(pub L, PhantomData<(S, A)>);
@@ -12,6 +20,120 @@ impl<S, A, L: Lens<S, A>> LensImpl<S, A, L> {
         //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
         LensImpl(l, PhantomData)
     }
+
+    /// Modifies the focus value with a fallible transformation.
+    ///
+    /// The source is only updated if `f` succeeds. If it fails, the source is left unchanged and
+    /// the error of `f` is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns the error of `f` if the transformation fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::mapped_lens;
+    ///
+    /// let lens = mapped_lens(|v: &u8| *v, |v, x| *v = x);
+    /// let add_50 = |x: u8| x.checked_add(50).ok_or("overflow");
+    /// let mut value = 200;
+    ///
+    /// assert_eq!(lens.try_modify_with(&mut value, add_50), Ok(()));
+    /// assert_eq!(value, 250);
+    /// assert_eq!(lens.try_modify_with(&mut value, add_50), Err("overflow"));
+    /// assert_eq!(value, 250);
+    /// ```
+    pub fn try_modify_with<E, F>(&self, source: &mut S, f: F) -> Result<(), E>
+    where
+        F: FnOnce(A) -> Result<A, E>,
+    {
+        let value = f(self.get(source))?;
+        self.set(source, value);
+        Ok(())
+    }
+
+    /// Maps the focus of this lens through an `Iso<A, B>`, resulting in a `LensImpl<S, B>`.
+    ///
+    /// Reading applies the iso's `get` to the focus, and writing applies its `reverse_get` before
+    /// setting the focus. This is the same as [`compose_with_iso`], named for the common case of
+    /// changing the representation of a lens' focus.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasSetter, HasTotalGetter, mapped_iso, mapped_lens};
+    ///
+    /// let celsius = mapped_lens(|t: &(f64, u8)| t.0, |t, c| t.0 = c);
+    /// let to_fahrenheit = mapped_iso(|c: &f64| c * 1.8 + 32.0, |f| (f - 32.0) / 1.8);
+    /// let fahrenheit = celsius.map_iso(to_fahrenheit);
+    ///
+    /// let mut reading = (100.0, 1);
+    /// assert_eq!(fahrenheit.get(&reading), 212.0);
+    ///
+    /// fahrenheit.set(&mut reading, 32.0);
+    /// assert_eq!(reading.0, 0.0);
+    /// ```
+    ///
+    /// [`compose_with_iso`]: LensImpl::compose_with_iso
+    pub fn map_iso<B, ISO: Iso<A, B>>(
+        self,
+        iso: IsoImpl<A, B, ISO>,
+    ) -> LensImpl<S, B, impl Lens<S, B>> {
+        self.compose_with_iso(iso)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<S, A, L: Lens<S, Vec<A>>> LensImpl<S, Vec<A>, L> {
+    /// Modifies the element at `index` of the focused `Vec` with `f`.
+    ///
+    /// If `index` is out of bounds, `f` is not called and the source is left unchanged. This is a
+    /// shorthand for focusing a single element of the collection without building an index optic
+    /// for every call.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::mapped_lens;
+    ///
+    /// let scores = mapped_lens(|t: &(u8, Vec<u32>)| t.1.clone(), |t, v| t.1 = v);
+    /// let mut team = (1, vec![10, 20, 30]);
+    ///
+    /// scores.modify_at(&mut team, 1, |s| s + 5);
+    /// assert_eq!(team.1, [10, 25, 30]);
+    ///
+    /// scores.modify_at(&mut team, 3, |s| s + 5);
+    /// assert_eq!(team.1, [10, 25, 30]);
+    /// ```
+    pub fn modify_at<F>(&self, source: &mut S, index: usize, f: F)
+    where
+        F: FnOnce(A) -> A,
+    {
+        let mut values = self.get(source);
+        if index >= values.len() {
+            return;
+        }
+        let value = values.swap_remove(index);
+        values.push(f(value));
+        let last = values.len() - 1;
+        values.swap(index, last);
+        self.set(source, values);
+    }
+}
+
+impl<S, A, L: Lens<S, A> + PartialEq> PartialEq for LensImpl<S, A, L> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<S, A, L: Lens<S, A> + Eq> Eq for LensImpl<S, A, L> {}
+
+impl<S, A, L: Lens<S, A> + fmt::Debug> fmt::Debug for LensImpl<S, A, L> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("LensImpl").field(&self.0).finish()
+    }
 }
 
 impl<S, A, L: Lens<S, A>> From<L> for LensImpl<S, A, L> {
@@ -34,6 +156,18 @@ impl<S, A, L: Lens<S, A>> HasSetter<S, A> for LensImpl<S, A, L> {
     }
 }
 
+impl<S, A, L: Lens<S, A> + HasRefGetter<S, A>> HasRefGetter<S, A> for LensImpl<S, A, L> {
+    type RefGetterError = L::RefGetterError;
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        self.0.try_get_ref(source)
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        self.0.try_get_mut(source)
+    }
+}
+
 impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
@@ -42,6 +176,68 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
         composed_lens(self.0, other.0)
     }
 
+    /// Composes this `LensImpl<S, I>` with a `Lens<I, A>`, both able to borrow their focus,
+    /// resulting in a `LensImpl<S, A>` that borrows its focus through both of them.
+    ///
+    /// Unlike [`compose_with_lens`], the intermediate `I` value is never cloned: reading borrows
+    /// it in place, and setting writes through a mutable borrow of it, so each level of the
+    /// structure is walked only once. The result also implements [`HasRefGetter`], so chains of
+    /// such compositions keep borrowing all the way down.
+    ///
+    /// Borrowing across both lenses requires the intermediate type to be `'static`, i.e. not to
+    /// hold any non-`'static` references itself.
+    ///
+    /// [`compose_with_lens`]: LensImpl::compose_with_lens
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasSetter, HasTotalRefGetter, field_lens};
+    ///
+    /// #[derive(Clone)]
+    /// struct Inner { value: u32 }
+    /// struct Outer { inner: Inner }
+    ///
+    /// let inner = field_lens(|o: &Outer| &o.inner, |o: &mut Outer| &mut o.inner);
+    /// let value = field_lens(|i: &Inner| &i.value, |i: &mut Inner| &mut i.value);
+    /// let path = inner.compose_with_ref_lens(value);
+    ///
+    /// let mut outer = Outer { inner: Inner { value: 1 } };
+    /// *path.get_mut(&mut outer) += 1;
+    /// assert_eq!(*path.get_ref(&outer), 2);
+    ///
+    /// path.set(&mut outer, 20);
+    /// assert_eq!(outer.inner.value, 20);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_ref_lens<A, L2>(
+        self,
+        other: LensImpl<I, A, L2>,
+    ) -> LensImpl<S, A, impl Lens<S, A> + HasRefGetter<S, A, RefGetterError = Infallible>>
+    where
+        I: 'static,
+        L: HasRefGetter<S, I, RefGetterError = Infallible>,
+        L2: Lens<I, A> + HasRefGetter<I, A, RefGetterError = Infallible>,
+    {
+        ref_composed_lens(self.0, other.0)
+    }
+
+    pub fn compose_with_getter<A, G2: Getter<I, A>>(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> GetterImpl<S, A, impl Getter<S, A>> {
+        composed_getter(self.0, other.0)
+    }
+
+    /// Composes this `LensImpl<S, I>` with a `PartialGetter<I, A>`, resulting in a
+    /// `PartialGetterImpl<S, A>` that fails exactly when `other` fails on the focus of `self`.
+    pub fn compose_with_partial_getter<A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG2::GetterError>> {
+        composed_partial_getter(self.0, other.0, infallible, identity)
+    }
+
     pub fn compose_with_prism<A, P: Prism<I, A>>(
         self,
         other: PrismImpl<I, A, P>,
@@ -62,4 +258,22 @@ impl<S, I, L: Lens<S, I>> LensImpl<S, I, L> {
     ) -> LensImpl<S, A, impl Lens<S, A>> {
         composed_lens(self.0, other.0)
     }
+
+    /// Composes this `LensImpl<S, I>` with a `Traversal<I, A>`, resulting in a `TraversalImpl<S, A>`
+    /// focusing on every focus value of `other` within the focus of `self`.
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: TraversalImpl<I, A, T2>,
+    ) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        composed_traversal(from_prism(self.0), other.0)
+    }
+
+    /// Composes this `LensImpl<S, I>` with a `Fold<I, A>`, resulting in a read-only
+    /// `FoldImpl<S, A>` focusing on every focus value of `other` within the focus of `self`.
+    pub fn compose_with_fold<A, F2: Fold<I, A>>(
+        self,
+        other: FoldImpl<I, A, F2>,
+    ) -> FoldImpl<S, A, impl Fold<S, A>> {
+        composed_fold(from_getter(self.0), other.0)
+    }
 }
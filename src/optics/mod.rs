@@ -1,9 +1,9 @@
 pub mod fallible_iso;
+pub mod fold;
 pub mod getter;
 pub mod iso;
 pub mod lens;
 pub mod partial_getter;
 pub mod prism;
 pub mod setter;
-
-// pub mod traversal;
\ No newline at end of file
+pub mod traversal;
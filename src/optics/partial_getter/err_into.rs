@@ -0,0 +1,44 @@
+use crate::{PartialGetter, PartialGetterImpl, mapped_partial_getter};
+
+/// Creates a `PartialGetter` reading the error of a `Result`, converted into another error type
+/// with [`From`].
+///
+/// Reading fails with `()` on `Ok`. This helps normalizing several error channels into a single
+/// application error type, e.g. before collecting errors from a [`Fold`].
+///
+/// The optics in this crate keep the same source and focus types on both sides, so there is no
+/// type-changing prism turning a `Result<T, E1>` into a `Result<T, E2>` when writing back. Map
+/// the error with [`Result::map_err`] for that direction instead.
+///
+/// [`Fold`]: crate::Fold
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, err_into_getter};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct AppError(String);
+///
+/// impl From<std::num::ParseIntError> for AppError {
+///     fn from(err: std::num::ParseIntError) -> Self {
+///         AppError(err.to_string())
+///     }
+/// }
+///
+/// let app_error = err_into_getter::<i32, _, AppError>();
+///
+/// assert_eq!(
+///     app_error.try_get(&"x".parse::<i32>()),
+///     Ok(AppError("invalid digit found in string".to_string()))
+/// );
+/// assert_eq!(app_error.try_get(&Ok(1)), Err(()));
+/// ```
+#[must_use]
+pub fn new<T, E1: Clone, E2: From<E1>>()
+-> PartialGetterImpl<Result<T, E1>, E2, impl PartialGetter<Result<T, E1>, E2, GetterError = ()>> {
+    mapped_partial_getter(|source: &Result<T, E1>| match source {
+        Ok(_) => Err(()),
+        Err(err) => Ok(E2::from(err.clone())),
+    })
+}
@@ -0,0 +1,27 @@
+use crate::{PartialGetter, PartialGetterImpl, mapped_partial_getter};
+use alloc::vec::Vec;
+
+/// Creates a `PartialGetter` reading the first element of a `Vec` that satisfies `predicate`.
+///
+/// Reading returns a clone of the first matching element, in order, or fails with `()` if no
+/// element matches. Composed after a lens focusing a collection, this reads e.g. "the first
+/// active item".
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, find_getter};
+///
+/// let first_even = find_getter(|n: &u32| n % 2 == 0);
+///
+/// assert_eq!(first_even.try_get(&vec![1, 4, 6]), Ok(4));
+/// assert_eq!(first_even.try_get(&vec![1, 3]), Err(()));
+/// ```
+#[must_use]
+pub fn new<A: Clone, P: Fn(&A) -> bool>(
+    predicate: P,
+) -> PartialGetterImpl<Vec<A>, A, impl PartialGetter<Vec<A>, A, GetterError = ()>> {
+    mapped_partial_getter(move |source: &Vec<A>| {
+        source.iter().find(|a| predicate(a)).cloned().ok_or(())
+    })
+}
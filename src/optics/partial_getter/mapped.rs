@@ -63,7 +63,7 @@ where
 /// - `S`: The source type of the optic
 /// - `A`: The target type of the optic
 /// - `E`: The error type returned when the focus fails
-
+///
 /// # Arguments
 ///
 /// - `get_fn` — A function that faillibly retrieves the focus value `A` from the source `S`.
@@ -86,7 +86,6 @@ where
 ///
 /// assert_eq!(x_partial_getter.try_get(&point), Ok(10));
 /// ```
-
 #[must_use]
 pub fn new<S, A, E, GET>(
     get_fn: GET,
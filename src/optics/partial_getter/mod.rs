@@ -1,12 +1,22 @@
-use std::convert::Infallible;
+use core::convert::Infallible;
 use crate::HasGetter;
 
 mod composed;
+mod err_into;
+#[cfg(feature = "alloc")]
+mod find;
 mod mapped;
+#[cfg(feature = "std")]
+mod weak;
 mod wrapper;
 
 pub use composed::new as composed_partial_getter;
+pub use err_into::new as err_into_getter;
+#[cfg(feature = "alloc")]
+pub use find::new as find_getter;
 pub use mapped::new as mapped_partial_getter;
+#[cfg(feature = "std")]
+pub use weak::{DroppedError, new as weak_upgrade_getter};
 pub use wrapper::PartialGetterImpl;
 
 /// A `PartialGetter` is an optic that focuses on a potential value inside a sum type, providing
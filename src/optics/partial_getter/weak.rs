@@ -0,0 +1,47 @@
+use crate::{PartialGetter, PartialGetterImpl, mapped_partial_getter};
+use core::fmt;
+use std::sync::Weak;
+
+/// The error returned by [`weak_upgrade_getter`] when the referent of the `Weak` was dropped.
+///
+/// [`weak_upgrade_getter`]: crate::weak_upgrade_getter
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DroppedError;
+
+impl fmt::Display for DroppedError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("weak reference referent was dropped")
+    }
+}
+
+impl core::error::Error for DroppedError {}
+
+/// Creates a `PartialGetter` reading the value behind a `Weak` reference.
+///
+/// Reading upgrades the `Weak` and clones the inner value while the `Arc` it points to is still
+/// alive, and fails with [`DroppedError`] once every strong reference has been dropped.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{DroppedError, HasGetter, weak_upgrade_getter};
+/// use std::sync::Arc;
+///
+/// let strong = Arc::new(7);
+/// let weak = Arc::downgrade(&strong);
+/// let upgrade = weak_upgrade_getter();
+///
+/// assert_eq!(upgrade.try_get(&weak), Ok(7));
+///
+/// drop(strong);
+/// assert_eq!(upgrade.try_get(&weak), Err(DroppedError));
+/// ```
+#[must_use]
+pub fn new<A: Clone>()
+-> PartialGetterImpl<Weak<A>, A, impl PartialGetter<Weak<A>, A, GetterError = DroppedError>> {
+    mapped_partial_getter(|weak: &Weak<A>| {
+        weak.upgrade()
+            .map(|strong| A::clone(&strong))
+            .ok_or(DroppedError)
+    })
+}
@@ -1,7 +1,7 @@
 use crate::optics::partial_getter::composed::new as composed_partial_getter;
 use crate::{
     FallibleIso, FallibleIsoImpl, Getter, GetterImpl, HasGetter, Iso, IsoImpl, Lens, LensImpl,
-    PartialGetter, Prism, PrismImpl, infallible,
+    PartialGetter, Prism, PrismImpl, infallible, mapped_getter,
 };
 use core::convert::identity;
 use core::marker::PhantomData;
@@ -15,8 +15,8 @@ use core::marker::PhantomData;
 /// 
 /// # Note
 /// 
-/// This struct is not intended to be created by users directly, but it implements a From<PartialGetter<S,A>> so
-/// that implementors of new optic types can wrap their concrete implementation of a PartialGetter optic.
+/// This struct is not intended to be created by users directly, but it implements a `From<PartialGetter<S,A>>` so
+/// that implementors of new optic types can wrap their concrete implementation of a `PartialGetter` optic.
 ///
 /// # Type Parameters
 ///
@@ -50,11 +50,36 @@ impl<S, A, PG: PartialGetter<S, A>> HasGetter<S, A> for PartialGetterImpl<S, A,
     }
 }
 
+impl<S, A, PG: PartialGetter<S, A>> PartialGetterImpl<S, A, PG> {
+    /// Turns this partial getter into a total `GetterImpl<S, A>`, computing the focus with `f`
+    /// from the error whenever the read fails.
+    ///
+    /// Unlike a fixed default value, the fallback can depend on why the read failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, mapped_partial_getter};
+    ///
+    /// let first_char = mapped_partial_getter(|s: &&str| s.chars().next().ok_or("empty"));
+    /// let initial = first_char.or_recover(|err| if err == "empty" { '?' } else { '!' });
+    ///
+    /// assert_eq!(initial.get(&"optics"), 'o');
+    /// assert_eq!(initial.get(&""), '?');
+    /// ```
+    pub fn or_recover<F: Fn(PG::GetterError) -> A>(
+        self,
+        f: F,
+    ) -> GetterImpl<S, A, impl Getter<S, A>> {
+        mapped_getter(move |source: &S| self.0.try_get(source).unwrap_or_else(&f))
+    }
+}
+
 impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
         self,
         other: PG2,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>>
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
     where
         PG1::GetterError: Into<E>,
         PG2::GetterError: Into<E>,
@@ -67,21 +92,21 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
         other: PG2,
         error_mapper1: fn(PG1::GetterError) -> E,
         error_mapper_2: fn(PG2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
         composed_partial_getter(self, other, error_mapper1, error_mapper_2)
     }
 
     pub fn compose_with_getter<A, G2: Getter<I, A>>(
         self,
         other: GetterImpl<I, A, G2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
         composed_partial_getter(self, other, identity, infallible)
     }
 
     pub fn compose_with_prism<E, A, P2: Prism<I, A>>(
         self,
         other: PrismImpl<I, A, P2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>>
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
     where
         PG1::GetterError: Into<E>,
         P2::GetterError: Into<E>,
@@ -94,7 +119,7 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
         other: PrismImpl<I, A, P2>,
         error_mapper_1: fn(PG1::GetterError) -> E,
         error_mapper_2: fn(P2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
         composed_partial_getter(
             self,
             PartialGetterImpl::new(other.0),
@@ -106,14 +131,14 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
     pub fn compose_with_lens<A, L2: Lens<I, A>>(
         self,
         other: LensImpl<I, A, L2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = PG1::GetterError>> {
         composed_partial_getter(self, other.0, identity, infallible)
     }
 
     pub fn compose_with_fallible_iso<E, A, FI2: FallibleIso<I, A>>(
         self,
         other: FallibleIsoImpl<I, A, FI2>,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>>
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
     where
         E: From<FI2::GetterError> + From<PG1::GetterError>,
     {
@@ -125,7 +150,7 @@ impl<S, I, PG1: PartialGetter<S, I>> PartialGetterImpl<S, I, PG1> {
         other: FallibleIsoImpl<I, A, FI2>,
         getter_error_mapper_1: fn(PG1::GetterError) -> E,
         getter_error_mapper_2: fn(FI2::GetterError) -> E,
-    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A>> {
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
         composed_partial_getter(self, other.0, getter_error_mapper_1, getter_error_mapper_2)
     }
 
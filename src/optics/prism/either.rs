@@ -0,0 +1,150 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+use either::Either;
+
+/// A `Prism` focusing on the `Left` payload of an `Either`.
+struct LeftPrism<L, R> {
+    phantom: PhantomData<(L, R)>,
+}
+
+/// A `Prism` focusing on the `Right` payload of an `Either`.
+struct RightPrism<L, R> {
+    phantom: PhantomData<(L, R)>,
+}
+
+impl<L: Clone, R> HasGetter<Either<L, R>, L> for LeftPrism<L, R> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &Either<L, R>) -> Result<L, Self::GetterError> {
+        source.as_ref().left().cloned().ok_or(())
+    }
+}
+
+impl<L, R> HasSetter<Either<L, R>, L> for LeftPrism<L, R> {
+    fn set(&self, source: &mut Either<L, R>, value: L) {
+        *source = Either::Left(value);
+    }
+}
+
+impl<L: Clone, R> HasReverseGet<Either<L, R>, L> for LeftPrism<L, R> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &L) -> Result<Either<L, R>, Self::ReverseError> {
+        Ok(Either::Left(value.clone()))
+    }
+}
+
+impl<L, R: Clone> HasGetter<Either<L, R>, R> for RightPrism<L, R> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &Either<L, R>) -> Result<R, Self::GetterError> {
+        source.as_ref().right().cloned().ok_or(())
+    }
+}
+
+impl<L, R> HasSetter<Either<L, R>, R> for RightPrism<L, R> {
+    fn set(&self, source: &mut Either<L, R>, value: R) {
+        *source = Either::Right(value);
+    }
+}
+
+impl<L, R: Clone> HasReverseGet<Either<L, R>, R> for RightPrism<L, R> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &R) -> Result<Either<L, R>, Self::ReverseError> {
+        Ok(Either::Right(value.clone()))
+    }
+}
+
+impl<L, R> PartialEq for LeftPrism<L, R> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<L, R> Eq for LeftPrism<L, R> {}
+
+impl<L, R> fmt::Debug for LeftPrism<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("left_prism")
+    }
+}
+
+impl<L, R> PartialEq for RightPrism<L, R> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<L, R> Eq for RightPrism<L, R> {}
+
+impl<L, R> fmt::Debug for RightPrism<L, R> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("right_prism")
+    }
+}
+
+/// Creates a `Prism` focusing on the payload of [`Either::Left`], failing with `()` on `Right`.
+///
+/// Setting or reverse-getting a value always results in `Left`. Only available with the `either`
+/// feature.
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use optics::{HasGetter, HasSetter, left_prism};
+///
+/// let mut value: Either<u8, char> = Either::Right('x');
+/// assert_eq!(left_prism().try_get(&value), Err(()));
+///
+/// left_prism().set(&mut value, 3);
+/// assert_eq!(value, Either::Left(3));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn left<L: Clone, R>() -> PrismImpl<
+    Either<L, R>,
+    L,
+    impl Prism<Either<L, R>, L, GetterError = ()>
+    + HasReverseGet<Either<L, R>, L, ReverseError = Infallible>
+    + Eq
+    + fmt::Debug,
+> {
+    LeftPrism {
+        phantom: PhantomData,
+    }
+    .into()
+}
+
+/// Creates a `Prism` focusing on the payload of [`Either::Right`], failing with `()` on `Left`.
+///
+/// Setting or reverse-getting a value always results in `Right`. Only available with the
+/// `either` feature.
+///
+/// # Examples
+///
+/// ```
+/// use either::Either;
+/// use optics::{HasGetter, right_prism};
+///
+/// let value: Either<u8, char> = Either::Right('x');
+/// assert_eq!(right_prism().try_get(&value), Ok('x'));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn right<L, R: Clone>() -> PrismImpl<
+    Either<L, R>,
+    R,
+    impl Prism<Either<L, R>, R, GetterError = ()>
+    + HasReverseGet<Either<L, R>, R, ReverseError = Infallible>
+    + Eq
+    + fmt::Debug,
+> {
+    RightPrism {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,72 @@
+use crate::{HasGetter, HasRefGetter, HasSetter, Prism, PrismImpl};
+
+/// A `Prism` focusing on an optional part of `S` through a pair of plain function pointers.
+struct FieldPrism<S, A> {
+    get: fn(&S) -> Option<&A>,
+    get_mut: fn(&mut S) -> Option<&mut A>,
+}
+
+impl<S, A: Clone> HasGetter<S, A> for FieldPrism<S, A> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (self.get)(source).cloned().ok_or(())
+    }
+}
+
+impl<S, A: Clone> HasSetter<S, A> for FieldPrism<S, A> {
+    fn set(&self, source: &mut S, value: A) {
+        if let Some(focus) = (self.get_mut)(source) {
+            *focus = value;
+        }
+    }
+}
+
+impl<S, A: Clone> HasRefGetter<S, A> for FieldPrism<S, A> {
+    type RefGetterError = ();
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        (self.get)(source).ok_or(())
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        (self.get_mut)(source).ok_or(())
+    }
+}
+
+/// Creates a `Prism` focusing on a value of `S` that may be absent, from a pair of function
+/// pointers optionally returning references to it.
+///
+/// This is the affine counterpart of [`field_lens`], suited to indexing into collections, e.g.
+/// `|v: &Vec<u8>| v.get(2)`. Reading fails with `()` when the value is absent, and setting is a
+/// no-op then. Present values are written in place through `get_mut`. The returned prism also
+/// implements [`HasRefGetter`], so it can be composed with
+/// [`PrismImpl::compose_with_ref_prism`] to borrow through a path of several levels.
+///
+/// # Parameters
+///
+/// - `get`: Borrows the value from the source, if present.
+/// - `get_mut`: Mutably borrows the value from the source, if present.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasSetter, field_prism};
+///
+/// let third = field_prism(|v: &Vec<u8>| v.get(2), |v: &mut Vec<u8>| v.get_mut(2));
+/// let mut bytes = vec![1, 2, 3];
+///
+/// third.set(&mut bytes, 9);
+/// assert_eq!(third.try_get(&bytes), Ok(9));
+/// assert_eq!(third.try_get(&vec![1]), Err(()));
+/// ```
+///
+/// [`field_lens`]: crate::field_lens
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<S, A: Clone>(
+    get: fn(&S) -> Option<&A>,
+    get_mut: fn(&mut S) -> Option<&mut A>,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = ()> + HasRefGetter<S, A, RefGetterError = ()>> {
+    FieldPrism { get, get_mut }.into()
+}
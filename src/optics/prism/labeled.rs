@@ -0,0 +1,74 @@
+use crate::{HasGetter, HasSetter, Prism, PrismImpl};
+use core::fmt;
+use core::marker::PhantomData;
+
+/// The error returned by a prism labeled with [`PrismImpl::labeled`], recording at which stage of
+/// a composed optic the read failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PathError<E> {
+    /// The label of the stage that failed.
+    pub stage: &'static str,
+    /// The error reported by the failing stage.
+    pub source: E,
+}
+
+impl<E: fmt::Display> fmt::Display for PathError<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed at stage '{}': {}", self.stage, self.source)
+    }
+}
+
+impl<E: core::error::Error + 'static> core::error::Error for PathError<E> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+/// A `Prism` wrapper tagging the errors of the inner prism with a stage label.
+struct LabeledPrism<S, A, E, P: Prism<S, A>> {
+    prism: P,
+    stage: &'static str,
+    phantom: PhantomData<(S, A, E)>,
+}
+
+impl<S, A, E, P> HasGetter<S, A> for LabeledPrism<S, A, E, P>
+where
+    P: Prism<S, A>,
+    P::GetterError: Into<E>,
+{
+    type GetterError = PathError<E>;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        self.prism.try_get(source).map_err(|e| PathError {
+            stage: self.stage,
+            source: e.into(),
+        })
+    }
+}
+
+impl<S, A, E, P> HasSetter<S, A> for LabeledPrism<S, A, E, P>
+where
+    P: Prism<S, A>,
+    P::GetterError: Into<E>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        self.prism.set(source, value);
+    }
+}
+
+#[must_use]
+pub fn new<S, A, E, P>(
+    prism: P,
+    stage: &'static str,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = PathError<E>>>
+where
+    P: Prism<S, A>,
+    P::GetterError: Into<E>,
+{
+    LabeledPrism {
+        prism,
+        stage,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,73 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, HasTotalReverseGet, Prism, PrismImpl};
+use alloc::format;
+use alloc::string::{String, ToString};
+use core::convert::Infallible;
+
+/// A `Prism` splitting a leading integer off a `String`.
+struct LeadingInt;
+
+impl HasGetter<String, (i64, String)> for LeadingInt {
+    type GetterError = ();
+
+    fn try_get(&self, source: &String) -> Result<(i64, String), Self::GetterError> {
+        let trimmed = source.trim_start();
+        let sign = usize::from(trimmed.starts_with('-'));
+        let digits = trimmed[sign..]
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(trimmed.len() - sign);
+        if digits == 0 {
+            return Err(());
+        }
+        let (number, rest) = trimmed.split_at(sign + digits);
+        let value = number.parse().map_err(|_| ())?;
+        Ok((value, rest.to_string()))
+    }
+}
+
+impl HasSetter<String, (i64, String)> for LeadingInt {
+    fn set(&self, source: &mut String, value: (i64, String)) {
+        *source = self.reverse_get(&value);
+    }
+}
+
+impl HasReverseGet<String, (i64, String)> for LeadingInt {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &(i64, String)) -> Result<String, Self::ReverseError> {
+        let (number, rest) = value;
+        Ok(format!("{number}{rest}"))
+    }
+}
+
+/// Creates a `Prism` splitting a leading integer off a `String`, focusing on the parsed value
+/// and the rest of the string.
+///
+/// Reading skips leading whitespace, then accepts an optional `-` sign followed by at least one
+/// ASCII digit, consuming as many digits as possible. It fails with `()` if there are no digits,
+/// or if the number does not fit in an `i64`. A `+` sign is not accepted. The rest is returned
+/// as is, including any whitespace following the number.
+///
+/// Reversing, and setting, prepend the rendered integer to the rest. As the skipped leading
+/// whitespace and any leading zeros are not part of the focus, they are lost when writing back.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasTotalReverseGet, leading_int_prism};
+///
+/// let leading = leading_int_prism();
+///
+/// assert_eq!(leading.try_get(&"  -42rest".to_string()), Ok((-42, "rest".to_string())));
+/// assert_eq!(leading.try_get(&"abc".to_string()), Err(()));
+/// assert_eq!(leading.reverse_get(&(7, " items".to_string())), "7 items");
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new() -> PrismImpl<
+    String,
+    (i64, String),
+    impl Prism<String, (i64, String), GetterError = ()>
+    + HasReverseGet<String, (i64, String), ReverseError = Infallible>,
+> {
+    LeadingInt.into()
+}
@@ -0,0 +1,70 @@
+use crate::{HasGetter, HasSetter, Prism, PrismImpl};
+use core::hash::Hash;
+use std::collections::HashMap;
+
+/// A `Prism` focusing on a `HashMap` entry only while it holds an expected value.
+struct MapEntryEq<K, V> {
+    key: K,
+    expected: V,
+}
+
+impl<K: Eq + Hash, V: PartialEq + Clone> HasGetter<HashMap<K, V>, V> for MapEntryEq<K, V> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &HashMap<K, V>) -> Result<V, Self::GetterError> {
+        match source.get(&self.key) {
+            Some(value) if *value == self.expected => Ok(value.clone()),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V: PartialEq + Clone> HasSetter<HashMap<K, V>, V> for MapEntryEq<K, V> {
+    fn set(&self, source: &mut HashMap<K, V>, value: V) {
+        if let Some(current) = source
+            .get_mut(&self.key)
+            .filter(|current| **current == self.expected)
+        {
+            *current = value;
+        }
+    }
+}
+
+/// Creates a `Prism` focusing on the value at `key` in a `HashMap`, but only while that value
+/// equals `expected`.
+///
+/// Reading fails with `()` if the key is missing or its value differs from `expected`. Setting
+/// is a compare-and-set: the value is only overwritten if it still equals `expected`, otherwise
+/// the map is left untouched. Missing keys are never inserted. This supports optimistic-
+/// concurrency-style updates, where a write must not clobber a concurrent change.
+///
+/// # Type Bounds
+///
+/// - `V: PartialEq` — to compare the current value against `expected`.
+/// - `V: Clone` — to return the matched value by value from `try_get`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasSetter, map_entry_eq};
+/// use std::collections::HashMap;
+///
+/// let mut versions = HashMap::from([("doc", 1)]);
+/// let at_v1 = map_entry_eq("doc", 1);
+///
+/// assert_eq!(at_v1.try_get(&versions), Ok(1));
+///
+/// at_v1.set(&mut versions, 2);
+/// assert_eq!(versions["doc"], 2);
+///
+/// // The entry no longer holds version 1, so this write is ignored.
+/// at_v1.set(&mut versions, 3);
+/// assert_eq!(versions["doc"], 2);
+/// ```
+#[must_use]
+pub fn new<K: Eq + Hash, V: PartialEq + Clone>(
+    key: K,
+    expected: V,
+) -> PrismImpl<HashMap<K, V>, V, impl Prism<HashMap<K, V>, V, GetterError = ()>> {
+    MapEntryEq { key, expected }.into()
+}
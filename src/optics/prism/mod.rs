@@ -3,11 +3,49 @@ use crate::HasSetter;
 use core::convert::Infallible;
 
 mod composed;
+#[cfg(feature = "either")]
+mod either;
+mod field;
+mod labeled;
+#[cfg(feature = "alloc")]
+mod leading_int;
+#[cfg(feature = "std")]
+mod map_entry;
 mod mapped;
+mod nonnull;
+mod opt;
+mod option;
+mod poll;
+mod ref_composed;
+mod result;
+mod reversible;
+#[cfg(feature = "alloc")]
+mod substr;
+mod variant;
+#[cfg(feature = "alloc")]
+mod vec_index;
 mod wrapper;
 
 pub use composed::new as composed_prism;
+#[cfg(feature = "either")]
+pub use either::{left as left_prism, right as right_prism};
+pub use field::new as field_prism;
+pub use labeled::PathError;
+#[cfg(feature = "alloc")]
+pub use leading_int::new as leading_int_prism;
+#[cfg(feature = "std")]
+pub use map_entry::new as map_entry_eq;
 pub use mapped::new as mapped_prism;
+pub use nonnull::new as nonnull_prism;
+pub use opt::new as prism_opt;
+pub use option::new as some_prism;
+pub use poll::{PendingError, new as poll_ready_prism};
+pub use result::new as ok_prism;
+#[cfg(feature = "alloc")]
+pub use substr::{SubstrError, new as substr_prism};
+pub use variant::new as variant_prism;
+#[cfg(feature = "alloc")]
+pub use vec_index::clamped as vec_index_clamped;
 pub use wrapper::PrismImpl;
 
 /// An optic that focuses on a part of a sum type, allowing for partial access and construction.
@@ -35,10 +73,10 @@ pub use wrapper::PrismImpl;
 /// # Notes
 ///
 /// - The setter should always construct a value, even if the getter would otherwise fail. Calling
-/// set on an Ok prism should always result in an Ok value, even if the previous focus was on an Err.
+///   set on an Ok prism should always result in an Ok value, even if the previous focus was on an Err.
 ///
 /// - Implementing this trait manually is generally discouraged unless you are working on a new prism implementation.
-/// Instead, use the provided implementations or constructors within the crate to ensure consistency and correctness.
+///   Instead, use the provided implementations or constructors within the crate to ensure consistency and correctness.
 ///
 /// # See Also
 ///
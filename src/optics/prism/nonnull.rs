@@ -0,0 +1,81 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+use core::ptr::NonNull;
+
+/// A `Prism` focusing on a raw pointer as a `NonNull`, when it is not null.
+struct NonNullPrism<T> {
+    phantom: PhantomData<*mut T>,
+}
+
+impl<T> HasGetter<*mut T, NonNull<T>> for NonNullPrism<T> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &*mut T) -> Result<NonNull<T>, Self::GetterError> {
+        NonNull::new(*source).ok_or(())
+    }
+}
+
+impl<T> HasSetter<*mut T, NonNull<T>> for NonNullPrism<T> {
+    fn set(&self, source: &mut *mut T, value: NonNull<T>) {
+        *source = value.as_ptr();
+    }
+}
+
+impl<T> HasReverseGet<*mut T, NonNull<T>> for NonNullPrism<T> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &NonNull<T>) -> Result<*mut T, Self::ReverseError> {
+        Ok(value.as_ptr())
+    }
+}
+
+impl<T> PartialEq for NonNullPrism<T> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T> Eq for NonNullPrism<T> {}
+
+impl<T> fmt::Debug for NonNullPrism<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("nonnull_prism")
+    }
+}
+
+/// Creates a `Prism` focusing on a raw pointer as a [`NonNull`], failing with `()` when the
+/// pointer is null.
+///
+/// The prism only checks for null, through [`NonNull::new`], and turns a `NonNull` back into a
+/// raw pointer through [`NonNull::as_ptr`]. Both are safe, so no `unsafe` code is involved, and
+/// the pointer is never dereferenced: a non-null pointer may still be dangling or misaligned.
+/// The prism can also construct a raw pointer directly through [`HasReverseGet`].
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, nonnull_prism};
+///
+/// let mut value = 7;
+/// let ptr: *mut i32 = &raw mut value;
+///
+/// assert_eq!(nonnull_prism().try_get(&ptr).map(|p| p.as_ptr()), Ok(ptr));
+/// assert_eq!(nonnull_prism::<i32>().try_get(&core::ptr::null_mut()), Err(()));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T>() -> PrismImpl<
+    *mut T,
+    NonNull<T>,
+    impl Prism<*mut T, NonNull<T>, GetterError = ()>
+    + HasReverseGet<*mut T, NonNull<T>, ReverseError = Infallible>
+    + Eq
+    + fmt::Debug,
+> {
+    NonNullPrism {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,99 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::marker::PhantomData;
+
+/// A `Prism` built from an `Option`-returning matcher and a constructor.
+struct OptPrism<S, A, GET, REV>
+where
+    GET: Fn(&S) -> Option<A>,
+    REV: Fn(A) -> S,
+{
+    get_fn: GET,
+    reverse_fn: REV,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, GET, REV> HasGetter<S, A> for OptPrism<S, A, GET, REV>
+where
+    GET: Fn(&S) -> Option<A>,
+    REV: Fn(A) -> S,
+{
+    type GetterError = ();
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        (self.get_fn)(source).ok_or(())
+    }
+}
+
+impl<S, A, GET, REV> HasSetter<S, A> for OptPrism<S, A, GET, REV>
+where
+    GET: Fn(&S) -> Option<A>,
+    REV: Fn(A) -> S,
+{
+    fn set(&self, source: &mut S, value: A) {
+        *source = (self.reverse_fn)(value);
+    }
+}
+
+impl<S, A: Clone, GET, REV> HasReverseGet<S, A> for OptPrism<S, A, GET, REV>
+where
+    GET: Fn(&S) -> Option<A>,
+    REV: Fn(A) -> S,
+{
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        Ok((self.reverse_fn)(value.clone()))
+    }
+}
+
+/// Creates a `Prism` from an `Option`-returning matcher and a constructor.
+///
+/// Many prisms are naturally expressed as a pattern match returning `Some` for the focused
+/// variant and `None` otherwise. This adapts such a matcher into a `PrismImpl` whose error type
+/// is `()`, which is friendlier than writing a matcher returning `Result`.
+///
+/// Setting a value through the resulting prism replaces the whole source with the one built by
+/// `reverse`, so the focused variant is always present afterwards. The constructor also lets the
+/// prism build a source directly through [`HasReverseGet`].
+///
+/// # Arguments
+///
+/// - `get_fn` — A function returning the focused value if the source matches, `None` otherwise.
+/// - `reverse_fn` — A function constructing a source from a focused value.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasSetter, HasTotalReverseGet, prism_opt};
+///
+/// let some = prism_opt(|o: &Option<u32>| *o, Some);
+///
+/// let mut value = None;
+/// assert_eq!(some.try_get(&value), Err(()));
+///
+/// some.set(&mut value, 42);
+/// assert_eq!(some.try_get(&value), Ok(42));
+/// assert_eq!(some.reverse_get(&7), Some(7));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<S, A: Clone, GET, REV>(
+    get_fn: GET,
+    reverse_fn: REV,
+) -> PrismImpl<
+    S,
+    A,
+    impl Prism<S, A, GetterError = ()> + HasReverseGet<S, A, ReverseError = Infallible>,
+>
+where
+    GET: Fn(&S) -> Option<A>,
+    REV: Fn(A) -> S,
+{
+    OptPrism {
+        get_fn,
+        reverse_fn,
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,75 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A `Prism` focusing on the payload of `Some`.
+struct SomePrism<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasGetter<Option<A>, A> for SomePrism<A> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &Option<A>) -> Result<A, Self::GetterError> {
+        source.clone().ok_or(())
+    }
+}
+
+impl<A> HasSetter<Option<A>, A> for SomePrism<A> {
+    fn set(&self, source: &mut Option<A>, value: A) {
+        *source = Some(value);
+    }
+}
+
+impl<A: Clone> HasReverseGet<Option<A>, A> for SomePrism<A> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &A) -> Result<Option<A>, Self::ReverseError> {
+        Ok(Some(value.clone()))
+    }
+}
+
+impl<A> PartialEq for SomePrism<A> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<A> Eq for SomePrism<A> {}
+
+impl<A> fmt::Debug for SomePrism<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("some_prism")
+    }
+}
+
+/// Creates a `Prism` focusing on the payload of `Some`, failing with `()` on `None`.
+///
+/// Setting a value always results in `Some`, and the prism can also construct a `Some` directly
+/// from a value through [`HasReverseGet`]. Like the other named optics, the returned prism
+/// implements `PartialEq`, `Eq` and `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, some_prism};
+///
+/// assert_eq!(some_prism().try_get(&Some(3)), Ok(3));
+/// assert_eq!(some_prism::<i32>().try_get(&None), Err(()));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<A: Clone>() -> PrismImpl<
+    Option<A>,
+    A,
+    impl Prism<Option<A>, A, GetterError = ()>
+    + HasReverseGet<Option<A>, A, ReverseError = Infallible>
+    + Eq
+    + fmt::Debug,
+> {
+    SomePrism {
+        phantom: PhantomData,
+    }
+    .into()
+}
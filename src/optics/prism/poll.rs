@@ -0,0 +1,81 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+use core::task::Poll;
+
+/// The error returned by [`poll_ready_prism`] when the source is `Poll::Pending`.
+///
+/// [`poll_ready_prism`]: crate::poll_ready_prism
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PendingError;
+
+impl fmt::Display for PendingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("poll is pending")
+    }
+}
+
+impl core::error::Error for PendingError {}
+
+/// A `Prism` focusing on the payload of `Poll::Ready`.
+struct PollReady<T> {
+    phantom: PhantomData<T>,
+}
+
+impl<T: Clone> HasGetter<Poll<T>, T> for PollReady<T> {
+    type GetterError = PendingError;
+
+    fn try_get(&self, source: &Poll<T>) -> Result<T, Self::GetterError> {
+        match source {
+            Poll::Ready(value) => Ok(value.clone()),
+            Poll::Pending => Err(PendingError),
+        }
+    }
+}
+
+impl<T: Clone> HasSetter<Poll<T>, T> for PollReady<T> {
+    fn set(&self, source: &mut Poll<T>, value: T) {
+        *source = Poll::Ready(value);
+    }
+}
+
+impl<T: Clone> HasReverseGet<Poll<T>, T> for PollReady<T> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &T) -> Result<Poll<T>, Self::ReverseError> {
+        Ok(Poll::Ready(value.clone()))
+    }
+}
+
+/// Creates a `Prism` focusing on the payload of `Poll::Ready`.
+///
+/// Reading fails with [`PendingError`] when the source is `Poll::Pending`. Setting a value always
+/// results in `Poll::Ready`, and the prism can also construct a `Poll::Ready` directly from a
+/// value through [`HasReverseGet`].
+///
+/// # Examples
+///
+/// ```
+/// use core::task::Poll;
+/// use optics::{HasGetter, HasTotalReverseGet, PendingError, poll_ready_prism};
+///
+/// let ready = poll_ready_prism::<u8>();
+///
+/// assert_eq!(ready.try_get(&Poll::Ready(3)), Ok(3));
+/// assert_eq!(ready.try_get(&Poll::Pending), Err(PendingError));
+/// assert_eq!(ready.reverse_get(&4), Poll::Ready(4));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: Clone>() -> PrismImpl<
+    Poll<T>,
+    T,
+    impl Prism<Poll<T>, T, GetterError = PendingError>
+    + HasReverseGet<Poll<T>, T, ReverseError = Infallible>,
+> {
+    PollReady {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,89 @@
+use crate::optics::prism::Prism;
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{HasGetter, HasRefGetter, HasSetter};
+use core::marker::PhantomData;
+
+/// A composed `Prism` borrowing through both of its prisms, instead of cloning the intermediate
+/// focus value.
+///
+/// Reading only clones the final focus, and setting writes through a mutable borrow of the
+/// intermediate value, so each level of the structure is visited once per operation.
+///
+/// This struct is automatically created by [`PrismImpl::compose_with_ref_prism`], and cannot be
+/// constructed manually.
+struct RefComposedPrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+    P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+{
+    optic1: P1,
+    optic2: P2,
+    error_fn_1: fn(P1::GetterError) -> E,
+    error_fn_2: fn(P2::GetterError) -> E,
+    _phantom: PhantomData<(S, I, A, E)>,
+}
+
+impl<P1, P2, E, S, I, A> HasGetter<S, A> for RefComposedPrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+    P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+{
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let i = self.optic1.try_get_ref(source).map_err(self.error_fn_1)?;
+        self.optic2.try_get(i).map_err(self.error_fn_2)
+    }
+}
+
+impl<P1, P2, E, S, I, A> HasSetter<S, A> for RefComposedPrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+    P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        if let Ok(i) = self.optic1.try_get_mut(source) {
+            self.optic2.set(i, value);
+        }
+    }
+}
+
+impl<P1, P2, E, S, I, A> HasRefGetter<S, A> for RefComposedPrism<P1, P2, E, S, I, A>
+where
+    I: 'static,
+    P1: Prism<S, I> + HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+    P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+{
+    type RefGetterError = E;
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        let i = self.optic1.try_get_ref(source).map_err(self.error_fn_1)?;
+        self.optic2.try_get_ref(i).map_err(self.error_fn_2)
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        let i = self.optic1.try_get_mut(source).map_err(self.error_fn_1)?;
+        self.optic2.try_get_mut(i).map_err(self.error_fn_2)
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn new<S, A, I: 'static, E, P1, P2>(
+    p1: P1,
+    p2: P2,
+    error_fn_1: fn(P1::GetterError) -> E,
+    error_fn_2: fn(P2::GetterError) -> E,
+) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E> + HasRefGetter<S, A, RefGetterError = E>>
+where
+    P1: Prism<S, I> + HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+    P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+{
+    RefComposedPrism {
+        optic1: p1,
+        optic2: p2,
+        error_fn_1,
+        error_fn_2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
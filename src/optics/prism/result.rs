@@ -0,0 +1,78 @@
+use crate::{HasGetter, HasReverseGet, HasSetter, Prism, PrismImpl};
+use core::convert::Infallible;
+use core::fmt;
+use core::marker::PhantomData;
+
+/// A `Prism` focusing on the payload of `Ok`.
+struct OkPrism<T, E> {
+    phantom: PhantomData<(T, E)>,
+}
+
+impl<T: Clone, E> HasGetter<Result<T, E>, T> for OkPrism<T, E> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &Result<T, E>) -> Result<T, Self::GetterError> {
+        match source {
+            Ok(value) => Ok(value.clone()),
+            Err(_) => Err(()),
+        }
+    }
+}
+
+impl<T, E> HasSetter<Result<T, E>, T> for OkPrism<T, E> {
+    fn set(&self, source: &mut Result<T, E>, value: T) {
+        *source = Ok(value);
+    }
+}
+
+impl<T: Clone, E> HasReverseGet<Result<T, E>, T> for OkPrism<T, E> {
+    type ReverseError = Infallible;
+
+    fn try_reverse_get(&self, value: &T) -> Result<Result<T, E>, Self::ReverseError> {
+        Ok(Ok(value.clone()))
+    }
+}
+
+impl<T, E> PartialEq for OkPrism<T, E> {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl<T, E> Eq for OkPrism<T, E> {}
+
+impl<T, E> fmt::Debug for OkPrism<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("ok_prism")
+    }
+}
+
+/// Creates a `Prism` focusing on the payload of `Ok`, failing with `()` on `Err`.
+///
+/// Setting a value always results in `Ok`, and the prism can also construct an `Ok` directly
+/// from a value through [`HasReverseGet`]. Like the other named optics, the returned prism
+/// implements `PartialEq`, `Eq` and `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, ok_prism};
+///
+/// assert_eq!(ok_prism::<i32, &str>().try_get(&Ok(3)), Ok(3));
+/// assert_eq!(ok_prism::<i32, &str>().try_get(&Err("boom")), Err(()));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<T: Clone, E>() -> PrismImpl<
+    Result<T, E>,
+    T,
+    impl Prism<Result<T, E>, T, GetterError = ()>
+    + HasReverseGet<Result<T, E>, T, ReverseError = Infallible>
+    + Eq
+    + fmt::Debug,
+> {
+    OkPrism {
+        phantom: PhantomData,
+    }
+    .into()
+}
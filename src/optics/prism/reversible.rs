@@ -0,0 +1,90 @@
+use crate::optics::prism::Prism;
+use crate::optics::prism::wrapper::PrismImpl;
+use crate::{HasGetter, HasReverseGet, HasSetter};
+use core::marker::PhantomData;
+
+/// A composed `Prism` that keeps the ability of both of its prisms to construct a source from a
+/// focus value.
+///
+/// Reversing goes through `optic2` first and then `optic1`, nesting the reconstructions. Both
+/// optics must fail with the same reverse error.
+///
+/// This struct is automatically created by [`PrismImpl::compose_with_reversible_prism`], and
+/// cannot be constructed manually.
+struct ReversiblePrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasReverseGet<S, I>,
+    P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+{
+    optic1: P1,
+    optic2: P2,
+    error_fn_1: fn(P1::GetterError) -> E,
+    error_fn_2: fn(P2::GetterError) -> E,
+    _phantom: PhantomData<(S, I, A, E)>,
+}
+
+impl<P1, P2, E, S, I, A> HasGetter<S, A> for ReversiblePrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasReverseGet<S, I>,
+    P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+{
+    type GetterError = E;
+
+    fn try_get(&self, source: &S) -> Result<A, Self::GetterError> {
+        let i = self.optic1.try_get(source).map_err(self.error_fn_1)?;
+        self.optic2.try_get(&i).map_err(self.error_fn_2)
+    }
+}
+
+impl<P1, P2, E, S, I, A> HasSetter<S, A> for ReversiblePrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasReverseGet<S, I>,
+    P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+{
+    fn set(&self, source: &mut S, value: A) {
+        if let Ok(mut i) = self.optic1.try_get(source) {
+            self.optic2.set(&mut i, value);
+            self.optic1.set(source, i);
+        }
+    }
+}
+
+impl<P1, P2, E, S, I, A> HasReverseGet<S, A> for ReversiblePrism<P1, P2, E, S, I, A>
+where
+    P1: Prism<S, I> + HasReverseGet<S, I>,
+    P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+{
+    type ReverseError = P1::ReverseError;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        let i = self.optic2.try_reverse_get(value)?;
+        self.optic1.try_reverse_get(&i)
+    }
+}
+
+/// Composes two prisms like [`composed_prism`](crate::composed_prism), but keeps the ability of
+/// both prisms to construct a source from a focus value.
+#[allow(clippy::type_complexity)]
+pub fn new<S, A, I, E, P1, P2>(
+    p1: P1,
+    p2: P2,
+    error_fn_1: fn(P1::GetterError) -> E,
+    error_fn_2: fn(P2::GetterError) -> E,
+) -> PrismImpl<
+    S,
+    A,
+    impl Prism<S, A, GetterError = E> + HasReverseGet<S, A, ReverseError = P1::ReverseError>,
+>
+where
+    P1: Prism<S, I> + HasReverseGet<S, I>,
+    P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+{
+    ReversiblePrism {
+        optic1: p1,
+        optic2: p2,
+        error_fn_1,
+        error_fn_2,
+        _phantom: PhantomData,
+    }
+    .into()
+}
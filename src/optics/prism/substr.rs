@@ -0,0 +1,109 @@
+use crate::{HasGetter, HasSetter, Prism, PrismImpl};
+use alloc::string::String;
+use core::fmt;
+use core::ops::Range;
+
+/// The error returned by [`substr_prism`] when its byte range does not select a substring.
+///
+/// [`substr_prism`]: crate::substr_prism
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SubstrError {
+    /// The range starts after it ends, or ends past the end of the string.
+    OutOfBounds {
+        /// The length of the string, in bytes.
+        len: usize,
+    },
+    /// The byte at `index`, one of the ends of the range, is inside a multi-byte character.
+    NotCharBoundary {
+        /// The offending end of the range.
+        index: usize,
+    },
+}
+
+impl fmt::Display for SubstrError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SubstrError::OutOfBounds { len } => {
+                write!(f, "byte range is out of bounds of a string of length {len}")
+            }
+            SubstrError::NotCharBoundary { index } => {
+                write!(f, "byte index {index} is not a char boundary")
+            }
+        }
+    }
+}
+
+impl core::error::Error for SubstrError {}
+
+/// A `Prism` focusing on the substring of a `String` at a fixed byte range.
+struct Substr {
+    range: Range<usize>,
+}
+
+impl Substr {
+    fn check(&self, source: &str) -> Result<(), SubstrError> {
+        let Range { start, end } = self.range;
+        if start > end || end > source.len() {
+            return Err(SubstrError::OutOfBounds { len: source.len() });
+        }
+        match [start, end]
+            .into_iter()
+            .find(|&i| !source.is_char_boundary(i))
+        {
+            Some(index) => Err(SubstrError::NotCharBoundary { index }),
+            None => Ok(()),
+        }
+    }
+}
+
+impl HasGetter<String, String> for Substr {
+    type GetterError = SubstrError;
+
+    fn try_get(&self, source: &String) -> Result<String, Self::GetterError> {
+        self.check(source)?;
+        Ok(String::from(&source[self.range.clone()]))
+    }
+}
+
+impl HasSetter<String, String> for Substr {
+    fn set(&self, source: &mut String, value: String) {
+        if self.check(source).is_ok() {
+            source.replace_range(self.range.clone(), &value);
+        }
+    }
+}
+
+/// Creates a `Prism` focusing on the substring of a `String` at the byte range `range`.
+///
+/// Reading fails with [`SubstrError::OutOfBounds`] if the range does not fit in the string, and
+/// with [`SubstrError::NotCharBoundary`] if either end falls inside a multi-byte character.
+/// Setting splices the new substring in place of the old one, keeping the text around it. The
+/// replacement may have a different length, in which case the text after it shifts. If the
+/// range does not select a substring, setting leaves the string unchanged.
+///
+/// # Notes
+///
+/// - This cannot be a fallible iso: the substring alone does not hold the surrounding text, so
+///   there is no way to rebuild the whole string from it. Setting relies on the source instead.
+/// - The range is fixed, so after a replacement of a different length it no longer covers the
+///   new substring.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasSetter, substr_prism};
+///
+/// let greeting = substr_prism(0..5);
+/// let mut text = "Hello, world".to_string();
+///
+/// assert_eq!(greeting.try_get(&text), Ok("Hello".to_string()));
+///
+/// greeting.set(&mut text, "Goodbye".to_string());
+/// assert_eq!(text, "Goodbye, world");
+/// ```
+#[must_use]
+pub fn new(
+    range: Range<usize>,
+) -> PrismImpl<String, String, impl Prism<String, String, GetterError = SubstrError>> {
+    Substr { range }.into()
+}
@@ -0,0 +1,57 @@
+use crate::{HasReverseGet, Prism, PrismImpl, prism_opt};
+use core::convert::Infallible;
+
+/// Creates a `Prism` focusing on one variant of an enum, given a function matching it and a
+/// function building it.
+///
+/// This is [`prism_opt`] under a name reading better at enum call sites, and behaves exactly
+/// like it. Variants with several fields are handled by focusing on a tuple of them, which can
+/// then be narrowed further by composing with other optics, e.g. [`tuple_lens`].
+///
+/// [`prism_opt`]: crate::prism_opt
+/// [`tuple_lens`]: crate::tuple_lens
+///
+/// # Arguments
+///
+/// - `matcher` — A function extracting the fields of the variant, or `None` for other variants.
+/// - `builder` — A function constructing the variant from its fields.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasTotalReverseGet, variant_prism};
+///
+/// #[derive(Debug, PartialEq)]
+/// enum Shape {
+///     Circle(f64),
+///     Rect { w: f64, h: f64 },
+/// }
+///
+/// let rect = variant_prism(
+///     |s: &Shape| match s {
+///         Shape::Rect { w, h } => Some((*w, *h)),
+///         _ => None,
+///     },
+///     |(w, h)| Shape::Rect { w, h },
+/// );
+///
+/// assert_eq!(rect.try_get(&Shape::Rect { w: 2.0, h: 3.0 }), Ok((2.0, 3.0)));
+/// assert_eq!(rect.try_get(&Shape::Circle(1.0)), Err(()));
+/// assert_eq!(rect.reverse_get(&(1.0, 1.0)), Shape::Rect { w: 1.0, h: 1.0 });
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<S, A: Clone, MATCH, BUILD>(
+    matcher: MATCH,
+    builder: BUILD,
+) -> PrismImpl<
+    S,
+    A,
+    impl Prism<S, A, GetterError = ()> + HasReverseGet<S, A, ReverseError = Infallible>,
+>
+where
+    MATCH: Fn(&S) -> Option<A>,
+    BUILD: Fn(A) -> S,
+{
+    prism_opt(matcher, builder)
+}
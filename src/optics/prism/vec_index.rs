@@ -0,0 +1,66 @@
+use crate::{HasGetter, HasSetter, Prism, PrismImpl};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A `Prism` focusing on the element of a `Vec` at an index clamped into its bounds.
+struct VecIndexClamped<A> {
+    index: usize,
+    phantom: PhantomData<A>,
+}
+
+impl<A> VecIndexClamped<A> {
+    fn clamped(&self, len: usize) -> Option<usize> {
+        len.checked_sub(1).map(|last| self.index.min(last))
+    }
+}
+
+impl<A: Clone> HasGetter<Vec<A>, A> for VecIndexClamped<A> {
+    type GetterError = ();
+
+    fn try_get(&self, source: &Vec<A>) -> Result<A, Self::GetterError> {
+        self.clamped(source.len())
+            .map(|index| source[index].clone())
+            .ok_or(())
+    }
+}
+
+impl<A: Clone> HasSetter<Vec<A>, A> for VecIndexClamped<A> {
+    fn set(&self, source: &mut Vec<A>, value: A) {
+        if let Some(index) = self.clamped(source.len()) {
+            source[index] = value;
+        }
+    }
+}
+
+/// Creates a `Prism` focusing on the element of a `Vec` at `index`, clamped to the last
+/// element if `index` is out of bounds.
+///
+/// Reading and writing both act on the element at `min(index, len - 1)`, so the prism only
+/// fails, with `()`, on an empty vector, where setting is a no-op. This suits cursors in UIs
+/// navigating a list that may shrink below the cursor position.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasGetter, HasSetter, vec_index_clamped};
+///
+/// let selected = vec_index_clamped(5);
+/// let mut items = vec!['a', 'b', 'c'];
+///
+/// assert_eq!(selected.try_get(&items), Ok('c'));
+///
+/// selected.set(&mut items, 'z');
+/// assert_eq!(items, ['a', 'b', 'z']);
+///
+/// assert_eq!(selected.try_get(&Vec::new()), Err(()));
+/// ```
+#[must_use]
+pub fn clamped<A: Clone>(
+    index: usize,
+) -> PrismImpl<Vec<A>, A, impl Prism<Vec<A>, A, GetterError = ()>> {
+    VecIndexClamped {
+        index,
+        phantom: PhantomData,
+    }
+    .into()
+}
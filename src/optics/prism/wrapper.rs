@@ -1,9 +1,17 @@
+use crate::optics::fold::getter::from_getter;
 use crate::optics::prism::composed::new as composed_prism;
+use crate::optics::prism::labeled::new as labeled_prism;
+use crate::optics::prism::ref_composed::new as ref_composed_prism;
+use crate::optics::prism::reversible::new as reversible_prism;
+use crate::optics::traversal::prism::from_prism;
 use crate::{
-    FallibleIso, FallibleIsoImpl, HasGetter, HasSetter, Iso, IsoImpl, Lens, LensImpl, Prism,
-    infallible,
+    FallibleIso, FallibleIsoImpl, Fold, FoldImpl, Getter, GetterImpl, HasGetter, HasRefGetter,
+    HasReverseGet, HasSetter, Iso, IsoImpl, Lens, LensImpl, PartialGetter, PartialGetterImpl,
+    PathError, Prism, Traversal, TraversalImpl, composed_fold, composed_partial_getter,
+    composed_traversal, infallible, mapped_getter,
 };
 use core::convert::identity;
+use core::fmt;
 use core::marker::PhantomData;
 
 /// Concrete implementation wrapper for a `Prism` optic.
@@ -69,6 +77,20 @@ impl<S, A, P: Prism<S, A>> PrismImpl<S, A, P> {
     }
 }
 
+impl<S, A, P: Prism<S, A> + PartialEq> PartialEq for PrismImpl<S, A, P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<S, A, P: Prism<S, A> + Eq> Eq for PrismImpl<S, A, P> {}
+
+impl<S, A, P: Prism<S, A> + fmt::Debug> fmt::Debug for PrismImpl<S, A, P> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("PrismImpl").field(&self.0).finish()
+    }
+}
+
 impl<S, A, P: Prism<S, A>> From<P> for PrismImpl<S, A, P> {
     fn from(value: P) -> Self {
         Self::new(value)
@@ -89,6 +111,113 @@ impl<S, A, P: Prism<S, A>> HasSetter<S, A> for PrismImpl<S, A, P> {
     }
 }
 
+impl<S, A, P: Prism<S, A> + HasRefGetter<S, A>> HasRefGetter<S, A> for PrismImpl<S, A, P> {
+    type RefGetterError = P::RefGetterError;
+
+    fn try_get_ref<'a>(&self, source: &'a S) -> Result<&'a A, Self::RefGetterError> {
+        self.0.try_get_ref(source)
+    }
+
+    fn try_get_mut<'a>(&self, source: &'a mut S) -> Result<&'a mut A, Self::RefGetterError> {
+        self.0.try_get_mut(source)
+    }
+}
+
+/// Prisms that can also construct a source from a focus value, like [`crate::poll_ready_prism`],
+/// keep that capability when wrapped.
+impl<S, A, P: Prism<S, A> + HasReverseGet<S, A>> HasReverseGet<S, A> for PrismImpl<S, A, P> {
+    type ReverseError = P::ReverseError;
+
+    fn try_reverse_get(&self, value: &A) -> Result<S, Self::ReverseError> {
+        self.0.try_reverse_get(value)
+    }
+}
+
+impl<S, A, P: Prism<S, A>> PrismImpl<S, A, P> {
+    /// Modifies the focus value with a fallible transformation, merging the errors of the optic
+    /// and of the transformation.
+    ///
+    /// The source is only updated if both the optic and `f` succeed. Otherwise it is left
+    /// unchanged, and the error of the optic, converted through `Into::into`, or the error of `f`
+    /// is returned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the optic fails to focus on a value, or if `f` fails.
+    pub fn try_modify_with<E, F>(&self, source: &mut S, f: F) -> Result<(), E>
+    where
+        P::GetterError: Into<E>,
+        F: FnOnce(A) -> Result<A, E>,
+    {
+        let value = f(self.try_get(source).map_err(Into::into)?)?;
+        self.set(source, value);
+        Ok(())
+    }
+
+    /// Turns this prism into a read-only, total `GetterImpl<S, A>`, computing the focus with `f`
+    /// from the error whenever the read fails.
+    ///
+    /// Unlike a fixed default value, the fallback can depend on why the read failed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasTotalGetter, ok_prism};
+    ///
+    /// let status = ok_prism::<u16, ()>().or_recover(|()| 500);
+    ///
+    /// assert_eq!(status.get(&Ok(200)), 200);
+    /// assert_eq!(status.get(&Err(())), 500);
+    /// ```
+    pub fn or_recover<F: Fn(P::GetterError) -> A>(
+        self,
+        f: F,
+    ) -> GetterImpl<S, A, impl Getter<S, A>> {
+        mapped_getter(move |source: &S| self.0.try_get(source).unwrap_or_else(&f))
+    }
+
+    /// Tags the errors of this prism with a stage label, turning them into a [`PathError`].
+    ///
+    /// Labeling every stage of a composed optic makes a failure report at which stage it
+    /// happened. The error of `self` is converted into `E` through `Into::into`, so stages with
+    /// different error types can share a common `PathError<E>` and still compose through
+    /// [`Compose`](crate::Compose).
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The error type wrapped by the resulting `PathError`.
+    ///
+    /// # Parameters
+    ///
+    /// - `stage`: The label reported when this prism fails.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{Compose, HasGetter, PathError, prism_opt};
+    ///
+    /// let payload = prism_opt(|s: &Option<&str>| *s, Some).labeled("payload");
+    /// let digit = prism_opt(|s: &&str| s.parse::<u8>().ok(), |_| "0").labeled("payload.parse");
+    /// let chain = payload.compose(digit);
+    ///
+    /// assert_eq!(chain.try_get(&Some("7")), Ok(7));
+    /// assert_eq!(
+    ///     chain.try_get(&Some("x")),
+    ///     Err(PathError::<()> { stage: "payload.parse", source: () })
+    /// );
+    /// ```
+    #[must_use]
+    pub fn labeled<E>(
+        self,
+        stage: &'static str,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = PathError<E>>>
+    where
+        P::GetterError: Into<E>,
+    {
+        labeled_prism(self.0, stage)
+    }
+}
+
 /// Composition methods for chaining a `PrismImpl` with other optic types,
 /// resulting in a new composed optic.
 ///
@@ -135,6 +264,92 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
         composed_prism(self, other, Into::into, Into::into)
     }
 
+    /// Composes this `PrismImpl<S,I>` with another `Prism<I,A>`, both able to borrow their focus,
+    /// resulting in a `PrismImpl<S, A>` that borrows its focus through both of them.
+    ///
+    /// Unlike [`compose_with_prism`](Self::compose_with_prism), the intermediate `I` value is
+    /// never cloned and written back: reading borrows it in place, and setting writes through a
+    /// mutable borrow of it, so each level of the structure is visited once. The result also
+    /// implements [`HasRefGetter`], so chains of such compositions keep borrowing all the way
+    /// down, and a value can be modified in place through `try_get_mut`.
+    ///
+    /// Borrowing across both prisms requires the intermediate type to be `'static`, i.e. not to
+    /// hold any non-`'static` references itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasGetter, HasRefGetter, field_prism};
+    /// use std::collections::HashMap;
+    ///
+    /// let second = field_prism(
+    ///     |v: &Vec<HashMap<String, u32>>| v.get(1),
+    ///     |v: &mut Vec<HashMap<String, u32>>| v.get_mut(1),
+    /// );
+    /// let hits = field_prism(
+    ///     |m: &HashMap<String, u32>| m.get("hits"),
+    ///     |m: &mut HashMap<String, u32>| m.get_mut("hits"),
+    /// );
+    /// let path = second.compose_with_ref_prism::<(), _, _>(hits);
+    ///
+    /// let mut pages = vec![HashMap::new(), HashMap::from([("hits".to_string(), 1)])];
+    /// *path.try_get_mut(&mut pages).unwrap() += 1;
+    /// assert_eq!(path.try_get(&pages), Ok(2));
+    /// assert_eq!(path.try_get(&vec![]), Err(()));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_ref_prism<E, A, P2>(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = E> + HasRefGetter<S, A, RefGetterError = E>>
+    where
+        I: 'static,
+        P1: HasRefGetter<S, I, RefGetterError = P1::GetterError>,
+        P2: Prism<I, A> + HasRefGetter<I, A, RefGetterError = P2::GetterError>,
+        P1::GetterError: Into<E>,
+        P2::GetterError: Into<E>,
+    {
+        ref_composed_prism(self.0, other.0, Into::into, Into::into)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with another `Prism<I,A>`, like
+    /// [`compose_with_prism`](Self::compose_with_prism), while keeping the ability of both prisms
+    /// to construct a source from a focus value.
+    ///
+    /// Reversing the composed prism reverses `other` first and then `self`, nesting the
+    /// reconstructions, e.g. turning `3` into `Ok(Some(3))` when composing a prism into `Ok` with
+    /// one into `Some`. Both prisms must fail with the same reverse error, which becomes the
+    /// reverse error of the composition.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasGetter, HasTotalReverseGet, ok_prism, some_prism};
+    ///
+    /// let ok_some = ok_prism::<Option<i32>, String>().compose_with_reversible_prism(some_prism());
+    ///
+    /// assert_eq!(ok_some.try_get(&Ok(Some(3))), Ok(3));
+    /// assert_eq!(ok_some.try_get(&Ok(None)), Err(()));
+    /// assert_eq!(ok_some.reverse_get(&4), Ok(Some(4)));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    pub fn compose_with_reversible_prism<E, A, P2>(
+        self,
+        other: PrismImpl<I, A, P2>,
+    ) -> PrismImpl<
+        S,
+        A,
+        impl Prism<S, A, GetterError = E> + HasReverseGet<S, A, ReverseError = P1::ReverseError>,
+    >
+    where
+        P1: HasReverseGet<S, I>,
+        P2: Prism<I, A> + HasReverseGet<I, A, ReverseError = P1::ReverseError>,
+        P1::GetterError: Into<E>,
+        P2::GetterError: Into<E>,
+    {
+        reversible_prism(self.0, other.0, Into::into, Into::into)
+    }
+
     /// Composes this `PrismImpl<S,I>` with another `Prism<I,A>`, resulting in a new `PrismImpl<S, A>`
     /// that focuses through both prisms sequentially.
     ///
@@ -172,6 +387,94 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
         composed_prism(self, other, error_mapper1, error_mapper_2)
     }
 
+    /// Composes this `PrismImpl<S,I>` with a `Getter<I,A>`, resulting in a new
+    /// `PartialGetterImpl<S, A>` that reads through both optics sequentially.
+    ///
+    /// The resulting `PartialGetterImpl` fails exactly when `self` fails to match.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `A`: The target type of the composed partial getter.
+    /// - `G2`: The type of the getter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The getter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
+    pub fn compose_with_getter<A, G2: Getter<I, A>>(
+        self,
+        other: GetterImpl<I, A, G2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = P1::GetterError>> {
+        composed_partial_getter(self.0, other.0, identity, infallible)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new
+    /// `PartialGetterImpl<S, A>` that reads through both optics sequentially.
+    ///
+    /// The resulting `PartialGetterImpl` fails if either `self` fails to match or `other` fails
+    /// on the matched value.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed partial getter.
+    /// - `A`: The target type of the composed partial getter.
+    /// - `PG2`: The type of the partial getter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The partial getter to compose with.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` that represents the composition of `self` and `other`.
+    ///
+    /// # Note
+    ///
+    /// This method uses `Into::into` to convert the errors from both optics into the
+    /// common error type `E`. If you need custom error mapping, consider using
+    /// [`compose_with_partial_getter_with_mappers`](Self::compose_with_partial_getter_with_mappers).
+    pub fn compose_with_partial_getter<E, A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>>
+    where
+        P1::GetterError: Into<E>,
+        PG2::GetterError: Into<E>,
+    {
+        composed_partial_getter(self.0, other.0, Into::into, Into::into)
+    }
+
+    /// Composes this `PrismImpl<S,I>` with a `PartialGetter<I,A>`, resulting in a new
+    /// `PartialGetterImpl<S, A>` that reads through both optics sequentially.
+    ///
+    /// # Type Parameters
+    ///
+    /// - `E`: The common error type for the composed partial getter.
+    /// - `A`: The target type of the composed partial getter.
+    /// - `PG2`: The type of the partial getter to compose with.
+    ///
+    /// # Parameters
+    ///
+    /// - `other`: The partial getter to compose with.
+    /// - `error_mapper_1`: A function to map `P1::GetterError` into `E`.
+    /// - `error_mapper_2`: A function to map `PG2::GetterError` into `E`.
+    ///
+    /// # Returns
+    ///
+    /// A new `PartialGetterImpl` that represents the composition of `self` and `other` with
+    /// custom error mapping.
+    pub fn compose_with_partial_getter_with_mappers<E, A, PG2: PartialGetter<I, A>>(
+        self,
+        other: PartialGetterImpl<I, A, PG2>,
+        error_mapper_1: fn(P1::GetterError) -> E,
+        error_mapper_2: fn(PG2::GetterError) -> E,
+    ) -> PartialGetterImpl<S, A, impl PartialGetter<S, A, GetterError = E>> {
+        composed_partial_getter(self.0, other.0, error_mapper_1, error_mapper_2)
+    }
+
     /// Composes this `PrismImpl<S,I>` with a `Lens<I,A>`, resulting in a new `PrismImpl<S, A>`
     /// that focuses through both optics sequentially.
     ///
@@ -295,4 +598,26 @@ impl<S, I, P1: Prism<S, I>> PrismImpl<S, I, P1> {
     ) -> PrismImpl<S, A, impl Prism<S, A, GetterError = P1::GetterError>> {
         composed_prism(self, other, identity, infallible)
     }
+
+    /// Composes this `PrismImpl<S, I>` with a `Traversal<I, A>`, resulting in a `TraversalImpl<S, A>`
+    /// focusing on every focus value of `other` within the focus of `self`.
+    ///
+    /// If this prism fails to match, the resulting traversal has no focus values.
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: TraversalImpl<I, A, T2>,
+    ) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        composed_traversal(from_prism(self.0), other.0)
+    }
+
+    /// Composes this `PrismImpl<S, I>` with a `Fold<I, A>`, resulting in a read-only
+    /// `FoldImpl<S, A>` focusing on every focus value of `other` within the focus of `self`.
+    ///
+    /// If this prism fails to match, the resulting fold has no focus values.
+    pub fn compose_with_fold<A, F2: Fold<I, A>>(
+        self,
+        other: FoldImpl<I, A, F2>,
+    ) -> FoldImpl<S, A, impl Fold<S, A>> {
+        composed_fold(from_getter(self.0), other.0)
+    }
 }
@@ -0,0 +1,85 @@
+use crate::{HasSetter, Setter, SetterImpl};
+use core::hash::Hash;
+use core::marker::PhantomData;
+use std::collections::HashMap;
+use std::collections::hash_map::Entry;
+
+/// A `Setter` merging a `HashMap` into the source map, combining the values of colliding keys.
+struct MapMerge<K, V, C: Fn(&mut V, V)> {
+    combine: C,
+    phantom: PhantomData<(K, V)>,
+}
+
+impl<K: Eq + Hash, V, C: Fn(&mut V, V)> HasSetter<HashMap<K, V>, HashMap<K, V>>
+    for MapMerge<K, V, C>
+{
+    fn set(&self, source: &mut HashMap<K, V>, value: HashMap<K, V>) {
+        for (key, incoming) in value {
+            match source.entry(key) {
+                Entry::Occupied(mut entry) => (self.combine)(entry.get_mut(), incoming),
+                Entry::Vacant(entry) => {
+                    entry.insert(incoming);
+                }
+            }
+        }
+    }
+}
+
+/// Creates a `Setter` that merges the given map into the source `HashMap`.
+///
+/// Keys missing from the source are inserted, and on key collisions the provided value
+/// overwrites the existing one. Keys only present in the source are left untouched. This is
+/// handy to apply a diff map onto a base map.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, map_merge};
+/// use std::collections::HashMap;
+///
+/// let mut base = HashMap::from([("a", 1), ("b", 2)]);
+/// map_merge().set(&mut base, HashMap::from([("b", 20), ("c", 30)]));
+///
+/// assert_eq!(base, HashMap::from([("a", 1), ("b", 20), ("c", 30)]));
+/// ```
+///
+/// # See Also
+///
+/// - [`map_merge_with`] — merges with a custom combine function instead of overwriting.
+///
+/// [`map_merge_with`]: crate::map_merge_with
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn new<K: Eq + Hash, V>()
+-> SetterImpl<HashMap<K, V>, HashMap<K, V>, impl Setter<HashMap<K, V>, HashMap<K, V>>> {
+    with(|existing, incoming| *existing = incoming)
+}
+
+/// Creates a `Setter` that merges the given map into the source `HashMap`, resolving key
+/// collisions with `combine`.
+///
+/// `combine` is called with the existing value, borrowed mutably so it can be updated in place,
+/// and the provided value. Keys present on only one side are kept as they are.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, map_merge_with};
+/// use std::collections::HashMap;
+///
+/// let mut totals = HashMap::from([("apples", 3), ("pears", 1)]);
+/// map_merge_with(|a, b| *a += b).set(&mut totals, HashMap::from([("apples", 2), ("plums", 4)]));
+///
+/// assert_eq!(totals, HashMap::from([("apples", 5), ("pears", 1), ("plums", 4)]));
+/// ```
+#[must_use]
+#[allow(clippy::type_complexity)]
+pub fn with<K: Eq + Hash, V, C: Fn(&mut V, V)>(
+    combine: C,
+) -> SetterImpl<HashMap<K, V>, HashMap<K, V>, impl Setter<HashMap<K, V>, HashMap<K, V>>> {
+    MapMerge {
+        combine,
+        phantom: PhantomData,
+    }
+    .into()
+}
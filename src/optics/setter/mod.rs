@@ -1,11 +1,19 @@
 mod composed;
+#[cfg(feature = "std")]
+mod map_merge;
 mod mapped;
+#[cfg(feature = "alloc")]
+mod sorted_insert;
 mod wrapper;
 
 use crate::HasSetter;
 
 pub use composed::new as composed_setter;
+#[cfg(feature = "std")]
+pub use map_merge::{new as map_merge, with as map_merge_with};
 pub use mapped::new as mapped_setter;
+#[cfg(feature = "alloc")]
+pub use sorted_insert::new as sorted_vec_insert;
 pub use wrapper::SetterImpl;
 
 /// An optic for focusing on a value that is guaranteed to exist within a larger structure.
@@ -0,0 +1,42 @@
+use crate::{HasSetter, Setter, SetterImpl};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A `Setter` inserting values into a sorted `Vec`, keeping it sorted.
+struct SortedVecInsert<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Ord> HasSetter<Vec<A>, A> for SortedVecInsert<A> {
+    fn set(&self, source: &mut Vec<A>, value: A) {
+        let index = source.partition_point(|existing| *existing <= value);
+        source.insert(index, value);
+    }
+}
+
+/// Creates a `Setter` that inserts the given value into a sorted `Vec`, at the position that
+/// keeps it sorted.
+///
+/// The insertion point is found by binary search. A value equal to elements already in the
+/// vector is inserted after all of them, so equal values stay in insertion order.
+///
+/// The source is expected to be sorted in ascending order already. If it is not, the value is
+/// still inserted somewhere, but the resulting order is unspecified.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasSetter, sorted_vec_insert};
+///
+/// let mut scores = vec![10, 30, 40];
+/// sorted_vec_insert().set(&mut scores, 20);
+///
+/// assert_eq!(scores, vec![10, 20, 30, 40]);
+/// ```
+#[must_use]
+pub fn new<A: Ord>() -> SetterImpl<Vec<A>, A, impl Setter<Vec<A>, A>> {
+    SortedVecInsert {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,58 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on every element of a fixed-size array, in order.
+struct ArrayEach<A, const N: usize> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone, const N: usize> HasFold<[A; N], A> for ArrayEach<A, N> {
+    fn try_fold<B, F>(&self, source: &[A; N], init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in source {
+            acc = f(acc, a.clone())?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<A: Clone, const N: usize> HasModifyAll<[A; N], A> for ArrayEach<A, N> {
+    fn modify_all<F>(&self, source: &mut [A; N], mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        for a in source.iter_mut() {
+            *a = f(a.clone());
+        }
+    }
+}
+
+/// Creates a `Traversal` focusing on all `N` elements of a `[A; N]` array, in order.
+///
+/// This is the fixed-size counterpart of [`each`]. It does not depend on `alloc`, and modifying
+/// writes the result of the closure back in place, so it does not allocate unless cloning an
+/// element does.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, HasModifyAll, array_each};
+///
+/// let mut rgb = [10u8, 20, 30];
+/// array_each().modify_all(&mut rgb, |c| c.saturating_mul(10));
+/// assert_eq!(rgb, [100, 200, 255]);
+/// assert_eq!(array_each().fold(&rgb, 0u32, |sum, c| sum + u32::from(c)), 555);
+/// ```
+///
+/// [`each`]: crate::each
+#[must_use]
+pub fn new<A: Clone, const N: usize>() -> TraversalImpl<[A; N], A, impl Traversal<[A; N], A>> {
+    ArrayEach {
+        phantom: PhantomData,
+    }
+    .into()
+}
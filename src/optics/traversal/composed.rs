@@ -0,0 +1,68 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A composed `Traversal` type, combining two traversals into a single traversal.
+///
+/// The focus values of the composition are the focus values of the second traversal, within each
+/// focus value of the first one, in order.
+///
+/// This struct is automatically created by the `compose_with_traversal` methods of the optic
+/// wrappers, and cannot be constructed manually.
+struct ComposedTraversal<T1: Traversal<S, I>, T2: Traversal<I, A>, S, I, A> {
+    optic1: T1,
+    optic2: T2,
+    _phantom: PhantomData<(S, I, A)>,
+}
+
+impl<T1, T2, S, I, A> ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I>,
+    T2: Traversal<I, A>,
+{
+    fn new(optic1: T1, optic2: T2) -> Self {
+        ComposedTraversal {
+            optic1,
+            optic2,
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<T1, T2, S, I, A> HasFold<S, A> for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I>,
+    T2: Traversal<I, A>,
+{
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        self.optic1
+            .try_fold(source, init, |acc, i| self.optic2.try_fold(&i, acc, &mut f))
+    }
+}
+
+impl<T1, T2, S, I, A> HasModifyAll<S, A> for ComposedTraversal<T1, T2, S, I, A>
+where
+    T1: Traversal<S, I>,
+    T2: Traversal<I, A>,
+{
+    fn modify_all<F>(&self, source: &mut S, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        self.optic1.modify_all(source, |mut i| {
+            self.optic2.modify_all(&mut i, &mut f);
+            i
+        });
+    }
+}
+
+#[must_use]
+pub fn new<S, A, I, T1: Traversal<S, I>, T2: Traversal<I, A>>(
+    t1: T1,
+    t2: T2,
+) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+    ComposedTraversal::new(t1, t2).into()
+}
@@ -0,0 +1,56 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on every element of a `Vec`, in order.
+struct Each<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasFold<Vec<A>, A> for Each<A> {
+    fn try_fold<B, F>(&self, source: &Vec<A>, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in source {
+            acc = f(acc, a.clone())?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<A: Clone> HasModifyAll<Vec<A>, A> for Each<A> {
+    fn modify_all<F>(&self, source: &mut Vec<A>, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        for a in source.iter_mut() {
+            *a = f(a.clone());
+        }
+    }
+}
+
+/// Creates a `Traversal` focusing on every element of a `Vec`, in order.
+///
+/// Both reading and modifying visit the elements one at a time, never collecting them into an
+/// intermediate collection. Modifying walks the vector with `iter_mut`, and writes the result of
+/// the closure back in place, so it does not allocate unless cloning an element does.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{each, HasFold, HasModifyAll};
+///
+/// let mut values = vec![1, 2, 3];
+/// each().modify_all(&mut values, |x| x + 1);
+/// assert_eq!(each().get_all(&values), vec![2, 3, 4]);
+/// ```
+#[must_use]
+pub fn new<A: Clone>() -> TraversalImpl<Vec<A>, A, impl Traversal<Vec<A>, A>> {
+    Each {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,59 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on every element of every inner `Vec` of a `Vec<Vec<A>>`.
+struct Flatten<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasFold<Vec<Vec<A>>, A> for Flatten<A> {
+    fn try_fold<B, F>(&self, source: &Vec<Vec<A>>, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in source.iter().flatten() {
+            acc = f(acc, a.clone())?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<A: Clone> HasModifyAll<Vec<Vec<A>>, A> for Flatten<A> {
+    fn modify_all<F>(&self, source: &mut Vec<Vec<A>>, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        for a in source.iter_mut().flatten() {
+            *a = f(a.clone());
+        }
+    }
+}
+
+/// Creates a `Traversal` focusing on every element of every inner vector of a `Vec<Vec<A>>`.
+///
+/// The elements are visited in row-major order: all elements of the first inner vector, then
+/// all elements of the second one, and so on. Modifying writes every result back in place, so
+/// the lengths of the inner vectors are preserved, and empty inner vectors are skipped.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, HasModifyAll, flatten_traversal};
+///
+/// let mut grid = vec![vec![1, 2], vec![], vec![3]];
+///
+/// assert_eq!(flatten_traversal().get_all(&grid), vec![1, 2, 3]);
+///
+/// flatten_traversal().modify_all(&mut grid, |x| x * 10);
+/// assert_eq!(grid, vec![vec![10, 20], vec![], vec![30]]);
+/// ```
+#[must_use]
+pub fn new<A: Clone>() -> TraversalImpl<Vec<Vec<A>>, A, impl Traversal<Vec<Vec<A>>, A>> {
+    Flatten {
+        phantom: PhantomData,
+    }
+    .into()
+}
@@ -0,0 +1,61 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl, lines_fold};
+use alloc::string::String;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on every line of a `String`.
+struct Lines;
+
+impl HasFold<String, String> for Lines {
+    fn try_fold<B, F>(&self, source: &String, init: B, f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, String) -> ControlFlow<B, B>,
+    {
+        lines_fold().try_fold(source, init, f)
+    }
+}
+
+impl HasModifyAll<String, String> for Lines {
+    fn modify_all<F>(&self, source: &mut String, mut f: F)
+    where
+        F: FnMut(String) -> String,
+    {
+        let mut rewritten = String::with_capacity(source.len());
+        for (index, line) in source.lines().enumerate() {
+            if index > 0 {
+                rewritten.push('\n');
+            }
+            rewritten.push_str(&f(String::from(line)));
+        }
+        if source.ends_with('\n') {
+            rewritten.push('\n');
+        }
+        *source = rewritten;
+    }
+}
+
+/// Creates a `Traversal` focusing on every line of a `String`, which can rewrite lines in place.
+///
+/// Lines are read like with [`lines_fold`]. When modifying, the rewritten lines are joined with
+/// `\n`, and a trailing line terminator of the source is kept. Note that this normalizes `\r\n`
+/// line terminators to `\n`.
+///
+/// # Allocation
+///
+/// Each line is cloned into a newly allocated `String` as it is visited, and modifying builds a
+/// new `String` replacing the source.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasModifyAll, lines_traversal};
+///
+/// let mut text = "a\nb\n".to_string();
+/// lines_traversal().modify_all(&mut text, |line| format!("- {line}"));
+/// assert_eq!(text, "- a\n- b\n");
+/// ```
+///
+/// [`lines_fold`]: crate::lines_fold
+#[must_use]
+pub fn new() -> TraversalImpl<String, String, impl Traversal<String, String>> {
+    Lines.into()
+}
@@ -0,0 +1,115 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A concrete implementation of the [`Traversal`] trait, built from closures.
+///
+/// The fold function returns the focus values of a source, in order, as anything that can be
+/// turned into an iterator. The modify function receives the source together with the
+/// transformation to apply to each of its focus values.
+struct MappedTraversal<S, A, GET, MODIFY, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+    MODIFY: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    get_all_fn: GET,
+    modify_all_fn: MODIFY,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, GET, MODIFY, ITER> MappedTraversal<S, A, GET, MODIFY, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+    MODIFY: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    fn new(get_all_fn: GET, modify_all_fn: MODIFY) -> Self {
+        MappedTraversal {
+            get_all_fn,
+            modify_all_fn,
+            phantom: PhantomData,
+        }
+    }
+}
+
+impl<S, A, GET, MODIFY, ITER> HasFold<S, A> for MappedTraversal<S, A, GET, MODIFY, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+    MODIFY: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in (self.get_all_fn)(source) {
+            acc = f(acc, a)?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<S, A, GET, MODIFY, ITER> HasModifyAll<S, A> for MappedTraversal<S, A, GET, MODIFY, ITER>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+    MODIFY: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    fn modify_all<F>(&self, source: &mut S, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        (self.modify_all_fn)(source, &mut f);
+    }
+}
+
+/// Creates a new `Traversal` with the provided fold and modify functions.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type of the optic
+/// - `A`: The type of the focus values
+///
+/// # Arguments
+///
+/// - `get_all_fn` — A function that returns the focus values of the source, in order.
+/// - `modify_all_fn` — A function that applies the given transformation to every focus value of
+///   the source.
+///
+/// # Returns
+///
+/// A new `TraversalImpl` instance that can be used as a `Traversal<S, A>`.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{mapped_traversal, HasFold, HasModifyAll};
+///
+/// struct Pair { left: i32, right: i32 }
+///
+/// let both = mapped_traversal(
+///     |p: &Pair| [p.left, p.right],
+///     |p: &mut Pair, f: &mut dyn FnMut(i32) -> i32| {
+///         p.left = f(p.left);
+///         p.right = f(p.right);
+///     },
+/// );
+///
+/// let mut pair = Pair { left: 1, right: 2 };
+/// both.modify_all(&mut pair, |x| x * 10);
+/// assert_eq!(both.get_all(&pair), vec![10, 20]);
+/// ```
+#[must_use]
+pub fn new<S, A, GET, MODIFY, ITER>(
+    get_all_fn: GET,
+    modify_all_fn: MODIFY,
+) -> TraversalImpl<S, A, impl Traversal<S, A>>
+where
+    GET: Fn(&S) -> ITER,
+    ITER: IntoIterator<Item = A>,
+    MODIFY: Fn(&mut S, &mut dyn FnMut(A) -> A),
+{
+    MappedTraversal::new(get_all_fn, modify_all_fn).into()
+}
@@ -1,2 +1,56 @@
+mod array;
+mod composed;
+#[cfg(feature = "alloc")]
+mod each;
+#[cfg(feature = "alloc")]
+mod flatten;
+#[cfg(feature = "alloc")]
+mod lines;
+mod mapped;
+mod option;
+pub(crate) mod prism;
+#[cfg(feature = "alloc")]
+mod removable;
+mod wrapper;
 
-//TODO
\ No newline at end of file
+use crate::{HasFold, HasModifyAll};
+
+pub use array::new as array_each;
+pub use composed::new as composed_traversal;
+#[cfg(feature = "alloc")]
+pub use each::new as each;
+#[cfg(feature = "alloc")]
+pub use flatten::new as flatten_traversal;
+#[cfg(feature = "alloc")]
+pub use lines::new as lines_traversal;
+pub use mapped::new as mapped_traversal;
+pub use option::new as option_each;
+#[cfg(feature = "alloc")]
+pub use removable::new as each_removable;
+pub use wrapper::TraversalImpl;
+
+/// An optic for focusing on zero or more values within a larger structure.
+///
+/// A `Traversal` is appropriate for collections and other structures containing several values
+/// of the same type, such as the elements of a `Vec`. All focus values can be read, in order,
+/// through [`HasFold`], and modified in place through [`HasModifyAll`].
+///
+/// # See Also
+///
+/// - [`Lens`] — optic for a single focus value that always exists
+/// - [`Prism`] — optic for a single focus value that may not exist
+///
+/// [`Lens`]: crate::Lens
+/// [`Prism`]: crate::Prism
+pub trait Traversal<S, A>: HasFold<S, A> + HasModifyAll<S, A> {}
+
+impl<S, A, TRAVERSAL: HasFold<S, A> + HasModifyAll<S, A>> Traversal<S, A> for TRAVERSAL {}
+
+/// Creates a `Traversal` that focuses on the source itself, as its only focus value.
+#[must_use]
+pub fn identity_traversal<S: Clone>() -> TraversalImpl<S, S, impl Traversal<S, S>> {
+    mapped_traversal(
+        |source: &S| core::iter::once(source.clone()),
+        |source: &mut S, f: &mut dyn FnMut(S) -> S| *source = f(source.clone()),
+    )
+}
@@ -0,0 +1,61 @@
+use crate::{HasFold, HasModifyAll, Traversal, TraversalImpl};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on the value of an `Option`, if there is one.
+struct OptionEach<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasFold<Option<A>, A> for OptionEach<A> {
+    fn try_fold<B, F>(&self, source: &Option<A>, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        match source {
+            Some(a) => f(init, a.clone()),
+            None => ControlFlow::Continue(init),
+        }
+    }
+}
+
+impl<A: Clone> HasModifyAll<Option<A>, A> for OptionEach<A> {
+    fn modify_all<F>(&self, source: &mut Option<A>, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        if let Some(a) = source.take() {
+            *source = Some(f(a));
+        }
+    }
+}
+
+/// Creates a `Traversal` focusing on the value of an `Option`, treating it as a container of
+/// zero or one elements.
+///
+/// `None` has no focus values, and `Some(a)` has exactly one, `a`. Modifying only calls the
+/// closure on `Some`, and never changes which variant the option holds. This lets code that is
+/// generic over folds and traversals handle `Option` just like a collection.
+///
+/// Unlike [`some_prism`], which fails on `None`, this traversal simply has nothing to focus on.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{HasFold, HasModifyAll, option_each};
+///
+/// let mut present = Some(20);
+/// option_each().modify_all(&mut present, |x| x + 1);
+/// assert_eq!(option_each().get_all(&present), vec![21]);
+///
+/// assert!(option_each::<i32>().get_all(&None).is_empty());
+/// ```
+///
+/// [`some_prism`]: crate::some_prism
+#[must_use]
+pub fn new<A: Clone>() -> TraversalImpl<Option<A>, A, impl Traversal<Option<A>, A>> {
+    OptionEach {
+        phantom: PhantomData,
+    }
+    .into()
+}
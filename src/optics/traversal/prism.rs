@@ -0,0 +1,42 @@
+use crate::{HasFold, HasModifyAll, Prism};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// Adapts a `Prism` (and thus any `Lens` or `Iso`) into a `Traversal` with zero or one focus
+/// values, so that it can be composed with other traversals.
+struct PrismTraversal<S, A, P: Prism<S, A>> {
+    prism: P,
+    phantom: PhantomData<(S, A)>,
+}
+
+impl<S, A, P: Prism<S, A>> HasFold<S, A> for PrismTraversal<S, A, P> {
+    fn try_fold<B, F>(&self, source: &S, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        match self.prism.try_get(source) {
+            Ok(value) => f(init, value),
+            Err(_) => ControlFlow::Continue(init),
+        }
+    }
+}
+
+impl<S, A, P: Prism<S, A>> HasModifyAll<S, A> for PrismTraversal<S, A, P> {
+    fn modify_all<F>(&self, source: &mut S, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        if let Ok(value) = self.prism.try_get(source) {
+            self.prism.set(source, f(value));
+        }
+    }
+}
+
+pub(crate) fn from_prism<S, A, P: Prism<S, A>>(
+    prism: P,
+) -> impl HasFold<S, A> + HasModifyAll<S, A> {
+    PrismTraversal {
+        prism,
+        phantom: PhantomData,
+    }
+}
@@ -0,0 +1,66 @@
+use crate::{HasFilterMapAll, HasFold, HasModifyAll, Traversal, TraversalImpl};
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A `Traversal` focusing on every element of a `Vec`, in order, which can also remove elements
+/// through [`HasFilterMapAll`].
+struct EachRemovable<A> {
+    phantom: PhantomData<A>,
+}
+
+impl<A: Clone> HasFold<Vec<A>, A> for EachRemovable<A> {
+    fn try_fold<B, F>(&self, source: &Vec<A>, init: B, mut f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        let mut acc = init;
+        for a in source {
+            acc = f(acc, a.clone())?;
+        }
+        ControlFlow::Continue(acc)
+    }
+}
+
+impl<A> HasModifyAll<Vec<A>, A> for EachRemovable<A> {
+    fn modify_all<F>(&self, source: &mut Vec<A>, mut f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        self.filter_map_all(source, |a| Some(f(a)));
+    }
+}
+
+impl<A> HasFilterMapAll<Vec<A>, A> for EachRemovable<A> {
+    fn filter_map_all<F>(&self, source: &mut Vec<A>, f: F)
+    where
+        F: FnMut(A) -> Option<A>,
+    {
+        *source = core::mem::take(source).into_iter().filter_map(f).collect();
+    }
+}
+
+/// Creates a `Traversal` focusing on every element of a `Vec`, in order, whose modification may
+/// also remove elements.
+///
+/// Besides the usual [`HasModifyAll::modify_all`], the returned traversal implements
+/// [`HasFilterMapAll::filter_map_all`], where returning `None` from the closure removes the
+/// element, in the spirit of [`Vec::retain`]. The retained elements keep their relative order.
+///
+/// # Examples
+///
+/// ```
+/// use optics::{each_removable, HasFilterMapAll};
+///
+/// let mut values = vec![1, 2, 3, 4];
+/// each_removable().filter_map_all(&mut values, |x| (x % 2 == 0).then_some(x * 10));
+/// assert_eq!(values, vec![20, 40]);
+/// ```
+#[must_use]
+pub fn new<A: Clone>()
+-> TraversalImpl<Vec<A>, A, impl Traversal<Vec<A>, A> + HasFilterMapAll<Vec<A>, A>> {
+    EachRemovable {
+        phantom: PhantomData,
+    }
+    .into()
+}
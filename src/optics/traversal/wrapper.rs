@@ -0,0 +1,97 @@
+use crate::optics::fold::enumerate::new as enumerated_fold;
+use crate::{
+    Fold, FoldImpl, HasFilterMapAll, HasFold, HasModifyAll, Traversal, composed_traversal,
+};
+use core::marker::PhantomData;
+use core::ops::ControlFlow;
+
+/// A wrapper of the [`Traversal`] optic implementations, encapsulating a traversal.
+///
+/// `TraversalImpl` provides a common interface for traversals, forwarding the fold and
+/// modification operations to the wrapped implementation.
+///
+/// # Type Parameters
+///
+/// - `S`: The source type of the optic
+/// - `A`: The type of the focus values
+/// - `TRAVERSAL`: The wrapped traversal implementation
+///
+/// # See Also
+///
+/// - [`Traversal`] — trait that `TraversalImpl` implements
+pub struct TraversalImpl<S, A, TRAVERSAL: Traversal<S, A>>(pub TRAVERSAL, PhantomData<(S, A)>);
+
+impl<S, A, TRAVERSAL: Traversal<S, A>> TraversalImpl<S, A, TRAVERSAL> {
+    fn new(t: TRAVERSAL) -> Self {
+        //TODO: Verify not to nest an Impl inside an Impl - currently seems to be impossible at compile time.
+        TraversalImpl(t, PhantomData)
+    }
+}
+
+impl<S, A, TRAVERSAL: Traversal<S, A>> From<TRAVERSAL> for TraversalImpl<S, A, TRAVERSAL> {
+    fn from(value: TRAVERSAL) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<S, A, TRAVERSAL: Traversal<S, A>> HasFold<S, A> for TraversalImpl<S, A, TRAVERSAL> {
+    fn try_fold<B, F>(&self, source: &S, init: B, f: F) -> ControlFlow<B, B>
+    where
+        F: FnMut(B, A) -> ControlFlow<B, B>,
+    {
+        self.0.try_fold(source, init, f)
+    }
+}
+
+impl<S, A, TRAVERSAL: Traversal<S, A>> HasModifyAll<S, A> for TraversalImpl<S, A, TRAVERSAL> {
+    fn modify_all<F>(&self, source: &mut S, f: F)
+    where
+        F: FnMut(A) -> A,
+    {
+        self.0.modify_all(source, f);
+    }
+}
+
+impl<S, A, TRAVERSAL> HasFilterMapAll<S, A> for TraversalImpl<S, A, TRAVERSAL>
+where
+    TRAVERSAL: Traversal<S, A> + HasFilterMapAll<S, A>,
+{
+    fn filter_map_all<F>(&self, source: &mut S, f: F)
+    where
+        F: FnMut(A) -> Option<A>,
+    {
+        self.0.filter_map_all(source, f);
+    }
+}
+
+impl<S, I, T1: Traversal<S, I>> TraversalImpl<S, I, T1> {
+    /// Composes this `TraversalImpl<S, I>` with another `Traversal<I, A>`, resulting in a
+    /// `TraversalImpl<S, A>` focusing on every focus value of `other` within every focus value of
+    /// `self`, in order.
+    #[must_use]
+    pub fn compose_with_traversal<A, T2: Traversal<I, A>>(
+        self,
+        other: TraversalImpl<I, A, T2>,
+    ) -> TraversalImpl<S, A, impl Traversal<S, A>> {
+        composed_traversal(self.0, other.0)
+    }
+}
+
+impl<S, A, TRAVERSAL: Traversal<S, A>> TraversalImpl<S, A, TRAVERSAL> {
+    /// Pairs every focus value with its 0-based position, in the order of [`HasFold::get_all`].
+    ///
+    /// The positions cannot be written back, so the result is a read-only [`FoldImpl`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use optics::{HasFold, each};
+    ///
+    /// let values = vec!['a', 'b'];
+    /// assert_eq!(each().enumerate().get_all(&values), vec![(0, 'a'), (1, 'b')]);
+    /// ```
+    #[must_use]
+    pub fn enumerate(self) -> FoldImpl<S, (usize, A), impl Fold<S, (usize, A)>> {
+        enumerated_fold(self.0)
+    }
+}
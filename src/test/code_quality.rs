@@ -20,8 +20,8 @@ fn optic_implementations_exported_struct_and_fns() {
 
     fn visit_item_struct(&mut self, i: &'ast ItemStruct) {
       if let Visibility::Public(_) = i.vis {
-        match self.current_module.iter().map(|s| s.as_str()).collect::<Vec<_>>().as_slice() {
-          [.., "mapped"] | [.., "composed"] => {
+        match self.current_module.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+          [.., "mapped" | "composed"] => {
             panic!("Found public struct in module {}::{}", self.current_module.join("::"), i.ident);
           }
           [.., "wrapped"] => {
@@ -29,9 +29,10 @@ fn optic_implementations_exported_struct_and_fns() {
 
             let expected_exported_type = format!("{optic_type}Impl");
 
-            if i.ident != expected_exported_type {
-              panic!("Found public struct in module {}::{} that is not a {}Impl", self.current_module.join("::"), i.ident, optic_type);
-            }
+            assert!(
+              i.ident == expected_exported_type,
+              "Found public struct in module {}::{} that is not a {}Impl", self.current_module.join("::"), i.ident, optic_type
+            );
           }
           _ => ()
         }
@@ -40,19 +41,21 @@ fn optic_implementations_exported_struct_and_fns() {
 
     fn visit_item_fn(&mut self, i: &'ast ItemFn) {
       if let Visibility::Public(_) = i.vis {
-        match self.current_module.iter().map(|s| s.as_str()).collect::<Vec<_>>().as_slice() {
-          [.., "mapped"] | [.., "composed"] => {
-            if i.sig.ident != "new" {
-              panic!("Found public fn in module {}::{} that is not new()", self.current_module.join("::"), i.sig.ident);
-            }
+        match self.current_module.iter().map(String::as_str).collect::<Vec<_>>().as_slice() {
+          [.., "mapped" | "composed"] => {
+            assert!(
+              i.sig.ident == "new",
+              "Found public fn in module {}::{} that is not new()", self.current_module.join("::"), i.sig.ident
+            );
           }
 
           [optic_type] => {
             let expected_exported_type = format!("identity_{optic_type}");
 
-            if i.sig.ident != expected_exported_type {
-              panic!("Found public fn in module {}::{} that is not {}", self.current_module.join("::"), i.sig.ident, expected_exported_type);
-            }
+            assert!(
+              i.sig.ident == expected_exported_type,
+              "Found public fn in module {}::{} that is not {}", self.current_module.join("::"), i.sig.ident, expected_exported_type
+            );
           }
 
           _ => ()
@@ -62,6 +65,6 @@ fn optic_implementations_exported_struct_and_fns() {
   }
 
   helpers::CRATE_AST.with(|syn|
-    Check::default().visit_file(&syn)
+    Check::default().visit_file(syn)
   );
 }
\ No newline at end of file
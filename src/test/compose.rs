@@ -0,0 +1,285 @@
+use crate::{
+    Compose, HasGetter, HasReverseGet, HasSetter, HasTotalGetter, HasTotalReverseGet, Iso, IsoImpl,
+    mapped_fallible_iso, mapped_getter, mapped_iso, mapped_lens, mapped_partial_getter,
+    mapped_prism,
+};
+
+#[derive(Debug, Clone, PartialEq)]
+struct Inner {
+    value: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Outer {
+    inner: Inner,
+}
+
+fn inner_lens() -> crate::LensImpl<Outer, Inner, impl crate::Lens<Outer, Inner>> {
+    mapped_lens(|o: &Outer| o.inner.clone(), |o, i| o.inner = i)
+}
+
+fn value_lens() -> crate::LensImpl<Inner, Option<u32>, impl crate::Lens<Inner, Option<u32>>> {
+    mapped_lens(|i: &Inner| i.value, |i, v| i.value = v)
+}
+
+#[test]
+fn compose_reproduces_compose_with_lens() {
+    let mut by_method = Outer {
+        inner: Inner { value: Some(1) },
+    };
+    let mut by_function = by_method.clone();
+
+    let method = inner_lens().compose_with_lens(value_lens());
+    let function = Compose::compose(inner_lens(), value_lens());
+
+    assert_eq!(function.get(&by_function), method.get(&by_method));
+
+    method.set(&mut by_method, Some(7));
+    function.set(&mut by_function, Some(7));
+
+    assert_eq!(by_function, by_method);
+    assert_eq!(function.get(&by_function), Some(7));
+}
+
+#[test]
+fn compose_picks_least_upper_bound() {
+    let mut outer = Outer {
+        inner: Inner { value: None },
+    };
+
+    let some_prism = mapped_prism(|v: &Option<u32>| v.ok_or(()), |v, x| *v = Some(x));
+    let doubled = mapped_iso(|x: &u32| x * 2, |x| x / 2);
+
+    let prism = inner_lens()
+        .compose(value_lens())
+        .compose(some_prism)
+        .compose(doubled);
+
+    assert_eq!(prism.try_get(&outer), Err(()));
+    outer.inner.value = Some(3);
+    assert_eq!(prism.try_get(&outer), Ok(6));
+
+    prism.set(&mut outer, 10);
+    assert_eq!(outer.inner.value, Some(5));
+}
+
+#[test]
+fn lens_composed_with_partial_getter_fails_on_focus() {
+    let mut outer = Outer {
+        inner: Inner { value: Some(3) },
+    };
+
+    let present = mapped_partial_getter(|v: &Option<u32>| v.ok_or("missing"));
+    let value = inner_lens()
+        .compose_with_lens(value_lens())
+        .compose_with_partial_getter(present);
+
+    assert_eq!(value.try_get(&outer), Ok(3));
+    outer.inner.value = None;
+    assert_eq!(value.try_get(&outer), Err("missing"));
+}
+
+#[test]
+fn prism_composed_with_partial_getter_merges_errors() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        NoValue,
+        Odd(u32),
+    }
+
+    let some_prism = mapped_prism(|v: &Option<u32>| v.ok_or(()), |v, x| *v = Some(x));
+    let even = mapped_partial_getter(|x: &u32| if x % 2 == 1 { Err(*x) } else { Ok(x / 2) });
+    let half =
+        some_prism.compose_with_partial_getter_with_mappers(even, |()| Error::NoValue, Error::Odd);
+
+    assert_eq!(half.try_get(&Some(8)), Ok(4));
+    assert_eq!(half.try_get(&Some(7)), Err(Error::Odd(7)));
+    assert_eq!(half.try_get(&None), Err(Error::NoValue));
+}
+
+#[test]
+fn compose_picks_partial_getter_for_read_only_tail() {
+    let outer = Outer {
+        inner: Inner { value: None },
+    };
+
+    let present = mapped_partial_getter(|v: &Option<u32>| v.ok_or(()));
+    let some_prism = mapped_prism(|o: &Outer| Ok::<_, ()>(o.inner.clone()), |o, i| o.inner = i);
+
+    let via_lens = inner_lens().compose(value_lens()).compose(present);
+    assert_eq!(via_lens.try_get(&outer), Err(()));
+
+    let present = mapped_partial_getter(|v: &Option<u32>| v.ok_or(()));
+    let via_prism = some_prism.compose(value_lens()).compose(present);
+    assert_eq!(via_prism.try_get(&outer), Err(()));
+}
+
+fn celsius_to_kelvin() -> IsoImpl<i32, i32, impl Iso<i32, i32>> {
+    mapped_iso(|c: &i32| c + 273, |k| k - 273)
+}
+
+#[test]
+fn lens_composed_with_iso_matches_applying_iso_separately() {
+    let mut outer = Outer {
+        inner: Inner { value: Some(10) },
+    };
+    let value = inner_lens().compose_with_lens(value_lens());
+    let doubled = mapped_iso(|v: &Option<u32>| v.map(|x| x * 2), |v| v.map(|x| x / 2));
+
+    let expected = doubled.get(&value.get(&outer));
+    let composed = inner_lens()
+        .compose_with_lens(value_lens())
+        .compose_with_iso(doubled);
+    assert_eq!(composed.get(&outer), expected);
+
+    composed.set(&mut outer, Some(40));
+    assert_eq!(outer.inner.value, Some(20));
+}
+
+#[test]
+fn prism_composed_with_iso_matches_applying_iso_separately() {
+    let positive = mapped_prism(
+        |x: &i32| if *x > 0 { Ok(*x) } else { Err("not positive") },
+        |x, v| *x = v,
+    );
+    let composed = positive.compose_with_iso(celsius_to_kelvin());
+
+    assert_eq!(composed.try_get(&5), Ok(celsius_to_kelvin().get(&5)));
+    assert_eq!(composed.try_get(&-5), Err("not positive"));
+
+    let mut source = 1;
+    composed.set(&mut source, 300);
+    assert_eq!(source, celsius_to_kelvin().reverse_get(&300));
+}
+
+#[test]
+fn fallible_iso_composed_with_iso_keeps_both_error_types() {
+    let small = mapped_fallible_iso(
+        |b: &u8| {
+            if *b < 100 {
+                Ok(i32::from(*b))
+            } else {
+                Err("too big")
+            }
+        },
+        |x: &i32| u8::try_from(*x).map_err(|_| *x),
+    );
+    let composed = small.compose_with_iso(celsius_to_kelvin());
+
+    assert_eq!(composed.try_get(&2), Ok(celsius_to_kelvin().get(&2)));
+    assert_eq!(composed.try_get(&200), Err("too big"));
+    assert_eq!(composed.try_reverse_get(&274), Ok(1));
+    assert_eq!(composed.try_reverse_get(&0), Err(-273));
+}
+
+#[test]
+fn getter_composed_with_iso_matches_applying_iso_separately() {
+    let length = mapped_getter(|s: &&str| i32::try_from(s.len()).unwrap_or(i32::MAX));
+    let composed = length.compose_with_iso(celsius_to_kelvin());
+
+    assert_eq!(composed.get(&"abc"), celsius_to_kelvin().get(&3));
+}
+
+#[test]
+fn partial_getter_composed_with_iso_matches_applying_iso_separately() {
+    let first = mapped_partial_getter(|v: &[i32; 2]| if v[0] == 0 { Err(()) } else { Ok(v[0]) });
+    let composed = first.compose(celsius_to_kelvin());
+
+    assert_eq!(composed.try_get(&[7, 0]), Ok(celsius_to_kelvin().get(&7)));
+    assert_eq!(composed.try_get(&[0, 7]), Err(()));
+}
+
+fn assert_prism<S, A, P: crate::Prism<S, A>>(_: &P) {}
+
+fn assert_partial_getter<S, A, PG: crate::PartialGetter<S, A>>(_: &PG) {}
+
+fn assert_getter<S, A, G: crate::Getter<S, A>>(_: &G) {}
+
+#[test]
+fn lens_and_prism_compose_to_prism_in_either_order() {
+    let present = mapped_prism(
+        |i: &Inner| i.value.ok_or(()),
+        |i, v| {
+            if i.value.is_some() {
+                i.value = Some(v);
+            }
+        },
+    );
+    let lens_first = inner_lens().compose(present);
+
+    let matching = mapped_prism(
+        |o: &Outer| o.inner.value.map(|_| o.inner.clone()).ok_or(()),
+        |o, i| o.inner = i,
+    );
+    let unwrapped = mapped_lens(
+        |i: &Inner| i.value.unwrap_or_default(),
+        |i, v| i.value = Some(v),
+    );
+    let prism_first = matching.compose(unwrapped);
+
+    assert_prism(&lens_first);
+    assert_prism(&prism_first);
+
+    for value in [None, Some(3)] {
+        let mut by_lens = Outer {
+            inner: Inner { value },
+        };
+        let mut by_prism = by_lens.clone();
+
+        assert_eq!(lens_first.try_get(&by_lens), prism_first.try_get(&by_prism));
+
+        lens_first.set(&mut by_lens, 7);
+        prism_first.set(&mut by_prism, 7);
+        assert_eq!(by_lens, by_prism);
+    }
+}
+
+#[test]
+fn getter_and_partial_getter_compose_to_partial_getter_in_either_order() {
+    let value = mapped_getter(|o: &Outer| o.inner.value);
+    let getter_first = value.compose(mapped_partial_getter(|v: &Option<u32>| v.ok_or(())));
+
+    let matching =
+        mapped_partial_getter(|o: &Outer| o.inner.value.map(|_| o.inner.clone()).ok_or(()));
+    let partial_getter_first =
+        matching.compose(mapped_getter(|i: &Inner| i.value.unwrap_or_default()));
+
+    assert_partial_getter(&getter_first);
+    assert_partial_getter(&partial_getter_first);
+
+    for value in [None, Some(3)] {
+        let outer = Outer {
+            inner: Inner { value },
+        };
+        assert_eq!(
+            getter_first.try_get(&outer),
+            partial_getter_first.try_get(&outer)
+        );
+    }
+}
+
+#[test]
+fn getter_composes_commutatively_with_iso_and_prism() {
+    let doubled = mapped_getter(|x: &i32| x * 2);
+
+    let iso_first = celsius_to_kelvin().compose(mapped_getter(|x: &i32| x * 2));
+    let getter_first = doubled.compose(celsius_to_kelvin());
+    assert_getter(&iso_first);
+    assert_getter(&getter_first);
+    assert_eq!(iso_first.get(&10), 566);
+    assert_eq!(getter_first.get(&10), 293);
+
+    let positive = || {
+        mapped_prism(
+            |x: &i32| if *x > 0 { Ok(*x) } else { Err(()) },
+            |x, v| *x = v,
+        )
+    };
+    let prism_first = positive().compose(mapped_getter(|x: &i32| x * 2));
+    let getter_first = mapped_getter(|x: &i32| x * 2).compose(positive());
+    assert_partial_getter(&prism_first);
+    assert_partial_getter(&getter_first);
+    for source in [-2, 0, 3] {
+        assert_eq!(prism_first.try_get(&source), getter_first.try_get(&source));
+    }
+}
@@ -0,0 +1,472 @@
+use crate::{
+    BlankError, Compose, EnvVarError, FallibleIso, HasGetter, HasReverseGet, HasSetter,
+    HasTotalGetter, HasTotalReverseGet, HexError, InvalidPermutation, OutOfRange, PartialGetter,
+    RatioError, StrEnumError, UnixTimeError, env_var, hex_iso, mapped_getter, mapped_iso,
+    mapped_partial_getter, non_empty_trimmed_iso, non_empty_trimmed_prism, parse_iso, permute_iso,
+    ratio_iso, str_enum_prism, system_time_iso, try_into_iso,
+};
+use alloc::collections::BTreeMap;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::convert::Infallible;
+use core::num::ParseIntError;
+
+fn assert_error_types<S, A, GE, RE, FI>(_: &FI)
+where
+    FI: FallibleIso<S, A, GetterError = GE, ReverseError = RE>,
+{
+}
+
+fn assert_partial_getter_error<S, A, E, PG>(_: &PG)
+where
+    PG: PartialGetter<S, A, GetterError = E>,
+{
+}
+
+#[test]
+fn parse_iso_parses_and_renders() {
+    let parse = parse_iso::<i32>();
+    assert_error_types::<String, i32, ParseIntError, Infallible, _>(&parse);
+
+    assert_eq!(parse.try_get(&"-12".to_string()), Ok(-12));
+    assert!(parse.try_get(&"twelve".to_string()).is_err());
+    assert_eq!(parse.try_reverse_get(&12), Ok("12".to_string()));
+
+    let mut source = "1".to_string();
+    parse.set(&mut source, 99);
+    assert_eq!(source, "99");
+}
+
+#[test]
+fn iso_composed_with_fallible_iso_keeps_only_fallible_errors() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Port(String);
+
+    let port = mapped_iso(|p: &Port| p.0.clone(), |s: &String| Port(s.clone()))
+        .compose_with_fallible_iso(parse_iso::<u16>());
+    assert_error_types::<Port, u16, ParseIntError, Infallible, _>(&port);
+
+    assert_eq!(port.try_get(&Port("8080".to_string())), Ok(8080));
+    assert!(port.try_get(&Port("http".to_string())).is_err());
+    assert_eq!(port.try_reverse_get(&443), Ok(Port("443".to_string())));
+
+    let mut source = Port("80".to_string());
+    port.set(&mut source, 8443);
+    assert_eq!(source, Port("8443".to_string()));
+}
+
+#[test]
+fn reversed_parse_iso_renders_then_parses() {
+    let render = parse_iso::<i32>().reverse();
+    assert_error_types::<i32, String, Infallible, ParseIntError, _>(&render);
+
+    assert_eq!(render.try_get(&42), Ok("42".to_string()));
+    assert_eq!(render.try_reverse_get(&"7".to_string()), Ok(7));
+    assert!(render.try_reverse_get(&"seven".to_string()).is_err());
+}
+
+#[test]
+fn reversed_set_ignores_unparsable_values() {
+    let render = parse_iso::<i32>().reverse();
+
+    let mut source = 1;
+    render.set(&mut source, "5".to_string());
+    assert_eq!(source, 5);
+
+    render.set(&mut source, "five".to_string());
+    assert_eq!(source, 5);
+}
+
+#[test]
+fn reversing_twice_restores_the_original_direction() {
+    let parse = parse_iso::<u8>().reverse().reverse();
+    assert_error_types::<String, u8, ParseIntError, Infallible, _>(&parse);
+
+    assert_eq!(parse.try_get(&"255".to_string()), Ok(255));
+    assert!(parse.try_get(&"256".to_string()).is_err());
+}
+
+#[test]
+fn permute_iso_round_trips_valid_permutation() {
+    let perm = permute_iso(vec![2, 0, 3, 1]);
+    let source = vec!["a", "b", "c", "d"];
+
+    let permuted = perm.try_get(&source).unwrap();
+    assert_eq!(permuted, vec!["c", "a", "d", "b"]);
+    assert_eq!(perm.try_reverse_get(&permuted), Ok(source.clone()));
+
+    let mut target = Vec::new();
+    perm.set(&mut target, permuted);
+    assert_eq!(target, source);
+}
+
+#[test]
+fn permute_iso_rejects_invalid_permutations() {
+    let source = vec![1, 2, 3];
+
+    assert_eq!(
+        permute_iso(vec![0, 1]).try_get(&source),
+        Err(InvalidPermutation)
+    );
+    assert_eq!(
+        permute_iso(vec![0, 1, 1]).try_get(&source),
+        Err(InvalidPermutation)
+    );
+    assert_eq!(
+        permute_iso(vec![0, 1, 3]).try_get(&source),
+        Err(InvalidPermutation)
+    );
+    assert_eq!(
+        permute_iso(vec![0, 1, 3]).try_reverse_get(&source),
+        Err(InvalidPermutation)
+    );
+
+    let mut unchanged = source.clone();
+    permute_iso(vec![0, 0, 0]).set(&mut unchanged, vec![7, 8, 9]);
+    assert_eq!(unchanged, source);
+}
+
+#[test]
+fn fallible_iso_try_modify_with_merges_parse_and_closure_errors() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Parse,
+        Negative,
+    }
+
+    impl From<ParseIntError> for Error {
+        fn from(_: ParseIntError) -> Self {
+            Error::Parse
+        }
+    }
+
+    let parse = parse_iso::<i32>();
+    let decrement = |x: i32| {
+        if x > 0 {
+            Ok(x - 1)
+        } else {
+            Err(Error::Negative)
+        }
+    };
+
+    let mut source = "3".to_string();
+    assert_eq!(parse.try_modify_with(&mut source, decrement), Ok(()));
+    assert_eq!(source, "2");
+
+    let mut zero = "0".to_string();
+    assert_eq!(
+        parse.try_modify_with(&mut zero, decrement),
+        Err(Error::Negative)
+    );
+    assert_eq!(zero, "0");
+
+    let mut garbage = "x".to_string();
+    assert_eq!(
+        parse.try_modify_with(&mut garbage, decrement),
+        Err(Error::Parse)
+    );
+    assert_eq!(garbage, "x");
+}
+
+#[test]
+fn fallible_iso_composed_with_getter_fails_only_on_validation() {
+    let is_even = mapped_getter(|n: &u32| n & 1 == 0);
+    let even = parse_iso::<u32>().compose_with_getter(is_even);
+    assert_partial_getter_error::<String, bool, ParseIntError, _>(&even);
+
+    assert_eq!(even.try_get(&"1234".to_string()), Ok(true));
+    assert_eq!(even.try_get(&"7".to_string()), Ok(false));
+    assert!(even.try_get(&"12a4".to_string()).is_err());
+}
+
+#[test]
+fn or_recover_derives_fallback_from_parse_error() {
+    let length = parse_iso::<usize>().or_recover(|err| err.to_string().len());
+    let expected = "x".parse::<usize>().unwrap_err().to_string().len();
+
+    assert_eq!(length.get(&"17".to_string()), 17);
+    assert_eq!(length.get(&"x".to_string()), expected);
+    assert_ne!(
+        length.get(&String::new()),
+        expected,
+        "an empty string fails with a different message"
+    );
+}
+
+#[test]
+fn fallible_iso_composed_with_partial_getter_merges_errors() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        Parse,
+        Zero,
+    }
+
+    impl From<ParseIntError> for Error {
+        fn from(_: ParseIntError) -> Self {
+            Error::Parse
+        }
+    }
+
+    let reciprocal = mapped_partial_getter(|x: &i32| {
+        if *x == 0 {
+            Err(Error::Zero)
+        } else {
+            Ok(100 / x)
+        }
+    });
+    let composed = parse_iso::<i32>().compose_with_partial_getter(reciprocal);
+
+    assert_eq!(composed.try_get(&"4".to_string()), Ok(25));
+    assert_eq!(composed.try_get(&"0".to_string()), Err(Error::Zero));
+    assert_eq!(composed.try_get(&"four".to_string()), Err(Error::Parse));
+}
+
+#[test]
+fn compose_picks_partial_getter_after_fallible_iso() {
+    let length = mapped_getter(|x: &i32| x.unsigned_abs());
+    let composed = parse_iso::<i32>().compose(length);
+
+    assert_eq!(composed.try_get(&"-7".to_string()), Ok(7));
+    assert!(composed.try_get(&"seven".to_string()).is_err());
+}
+
+#[test]
+fn hex_iso_round_trips_bytes() {
+    let hex = hex_iso::<4>();
+    let bytes = [0x00, 0x7f, 0xab, 0xff];
+
+    let rendered = hex.reverse_get(&bytes);
+    assert_eq!(rendered, "007fabff");
+    assert_eq!(hex.try_get(&rendered), Ok(bytes));
+    assert_eq!(hex.try_get(&"007FABFF".to_string()), Ok(bytes));
+    assert_eq!(hex_iso::<0>().try_get(&String::new()), Ok([]));
+}
+
+#[test]
+fn hex_iso_reports_length_and_digit_errors_distinctly() {
+    let hex = hex_iso::<2>();
+
+    assert_eq!(
+        hex.try_get(&"abc".to_string()),
+        Err(HexError::Length {
+            expected: 4,
+            found: 3
+        })
+    );
+    assert_eq!(
+        hex.try_get(&"abcdef".to_string()),
+        Err(HexError::Length {
+            expected: 4,
+            found: 6
+        })
+    );
+    assert_eq!(
+        hex.try_get(&"ab-d".to_string()),
+        Err(HexError::InvalidDigit {
+            index: 2,
+            found: '-'
+        })
+    );
+    assert_eq!(
+        hex.try_get(&"abcé".to_string()),
+        Err(HexError::InvalidDigit {
+            index: 3,
+            found: 'é'
+        })
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Mode {
+    Fast,
+    Safe,
+    Debug,
+}
+
+#[test]
+fn str_enum_prism_round_trips_mapped_variants() {
+    let mode = str_enum_prism(&[("fast", Mode::Fast), ("safe", Mode::Safe)]);
+
+    for (name, value) in [("fast", Mode::Fast), ("safe", Mode::Safe)] {
+        assert_eq!(mode.try_get(&name.to_string()), Ok(value.clone()));
+        assert_eq!(mode.try_reverse_get(&value), Ok(name.to_string()));
+    }
+
+    let mut source = "fast".to_string();
+    mode.set(&mut source, Mode::Safe);
+    assert_eq!(source, "safe");
+}
+
+#[test]
+fn str_enum_prism_rejects_unknown_and_unmapped_values() {
+    let mode = str_enum_prism(&[("fast", Mode::Fast), ("safe", Mode::Safe)]);
+
+    assert_eq!(
+        mode.try_get(&"turbo".to_string()),
+        Err(StrEnumError::UnknownString("turbo".to_string()))
+    );
+    assert_eq!(
+        mode.try_reverse_get(&Mode::Debug),
+        Err(StrEnumError::UnmappedValue)
+    );
+
+    let mut source = "fast".to_string();
+    mode.set(&mut source, Mode::Debug);
+    assert_eq!(source, "fast");
+}
+
+#[test]
+fn try_into_iso_narrows_fitting_values_and_widens_back() {
+    let narrow = try_into_iso::<i64, i32>();
+    assert_error_types::<i64, i32, OutOfRange<i64>, Infallible, _>(&narrow);
+
+    assert_eq!(narrow.try_get(&-123), Ok(-123));
+    assert_eq!(narrow.reverse_get(&i32::MIN), i64::from(i32::MIN));
+
+    let mut wide = 0i64;
+    narrow.set(&mut wide, 9);
+    assert_eq!(wide, 9);
+}
+
+#[test]
+fn try_into_iso_reports_out_of_range_original() {
+    let narrow = try_into_iso::<i64, i32>();
+    let too_big = i64::from(i32::MAX) + 1;
+
+    assert_eq!(narrow.try_get(&too_big), Err(OutOfRange(too_big)));
+    assert_eq!(
+        try_into_iso::<u16, u8>().try_get(&256),
+        Err(OutOfRange(256))
+    );
+}
+
+fn env() -> BTreeMap<String, String> {
+    BTreeMap::from([
+        ("PORT".to_string(), "8080".to_string()),
+        ("DEBUG".to_string(), "yes".to_string()),
+    ])
+}
+
+#[test]
+fn env_var_parses_present_keys_and_inserts_on_set() {
+    let mut env = env();
+    let port = env_var::<u16>("PORT");
+
+    assert_eq!(port.try_get(&env), Ok(8080));
+
+    env_var::<u32>("WORKERS").set(&mut env, 4);
+    assert_eq!(env["WORKERS"], "4");
+    assert_eq!(env.len(), 3);
+    assert_eq!(
+        port.try_reverse_get(&1),
+        Ok(BTreeMap::from([("PORT".to_string(), "1".to_string())]))
+    );
+}
+
+#[test]
+fn env_var_distinguishes_missing_keys_from_parse_failures() {
+    let env = env();
+
+    assert_eq!(
+        env_var::<u16>("HOST").try_get(&env),
+        Err(EnvVarError::Missing("HOST".to_string()))
+    );
+    assert!(matches!(
+        env_var::<bool>("DEBUG").try_get(&env),
+        Err(EnvVarError::Parse(_))
+    ));
+}
+
+#[test]
+fn ratio_iso_divides_and_recovers_simple_fractions() {
+    let ratio = ratio_iso();
+
+    assert_eq!(ratio.try_get(&(-3, 8)), Ok(-0.375));
+    assert_eq!(ratio.try_reverse_get(&(1.0 / 3.0)), Ok((1, 3)));
+    assert_eq!(ratio.try_reverse_get(&(-0.375)), Ok((-3, 8)));
+    assert_eq!(ratio.try_reverse_get(&2.0), Ok((2, 1)));
+
+    let (numerator, denominator) = ratio.try_reverse_get(&core::f64::consts::PI).unwrap();
+    assert!(denominator <= 1_000_000);
+    assert!(
+        (ratio.try_get(&(numerator, denominator)).unwrap() - core::f64::consts::PI).abs() < 1e-9
+    );
+}
+
+#[test]
+fn ratio_iso_rejects_zero_denominators_and_non_finite_floats() {
+    let ratio = ratio_iso();
+
+    assert_eq!(ratio.try_get(&(5, 0)), Err(RatioError::ZeroDenominator));
+    assert_eq!(
+        ratio.try_reverse_get(&f64::NAN),
+        Err(RatioError::NotRepresentable)
+    );
+    assert_eq!(
+        ratio.try_reverse_get(&f64::INFINITY),
+        Err(RatioError::NotRepresentable)
+    );
+}
+
+#[test]
+fn non_empty_trimmed_prism_trims_and_rejects_blank_strings() {
+    let prism = non_empty_trimmed_prism();
+
+    assert_eq!(
+        prism.try_get(&"  hello world\n".to_string()),
+        Ok("hello world".to_string())
+    );
+    assert_eq!(prism.try_get(&" \t\n".to_string()), Err(BlankError));
+    assert_eq!(prism.try_get(&String::new()), Err(BlankError));
+    assert_eq!(
+        prism.try_reverse_get(&" x ".to_string()),
+        Ok("x".to_string())
+    );
+
+    let mut source = String::new();
+    prism.set(&mut source, "  set ".to_string());
+    assert_eq!(source, "set");
+}
+
+#[test]
+fn non_empty_trimmed_iso_rejects_blank_strings_both_ways() {
+    let iso = non_empty_trimmed_iso();
+
+    assert_eq!(iso.try_get(&" name ".to_string()), Ok("name".to_string()));
+    assert_eq!(iso.try_get(&"   ".to_string()), Err(BlankError));
+    assert_eq!(
+        iso.try_reverse_get(&"name".to_string()),
+        Ok("name".to_string())
+    );
+    assert_eq!(iso.try_reverse_get(&String::new()), Err(BlankError));
+}
+
+#[test]
+fn system_time_iso_round_trips_known_timestamps() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let unix = system_time_iso();
+    let moon_landing = UNIX_EPOCH + Duration::from_secs(14_182_940);
+
+    assert_eq!(unix.try_get(&moon_landing), Ok(14_182_940));
+    assert_eq!(unix.try_reverse_get(&14_182_940), Ok(moon_landing));
+    assert_eq!(
+        unix.try_get(&(moon_landing + Duration::from_millis(999))),
+        Ok(14_182_940)
+    );
+    assert_eq!(unix.try_get(&UNIX_EPOCH), Ok(0));
+}
+
+#[test]
+fn system_time_iso_rejects_pre_epoch_times() {
+    use std::time::{Duration, UNIX_EPOCH};
+
+    let unix = system_time_iso();
+
+    assert_eq!(
+        unix.try_get(&(UNIX_EPOCH - Duration::from_secs(1))),
+        Err(UnixTimeError::PreEpoch)
+    );
+    assert_eq!(unix.try_reverse_get(&-86_400), Err(UnixTimeError::PreEpoch));
+}
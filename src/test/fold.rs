@@ -0,0 +1,167 @@
+use crate::{
+    HasFold, HasModifyAll, each, lines_fold, lines_traversal, mapped_fold, mapped_getter,
+    mapped_iso, mapped_lens, mapped_prism, windows_fold, words_fold,
+};
+use alloc::string::ToString;
+use alloc::vec;
+use alloc::vec::Vec;
+
+const TEXT: &str = "the quick brown\nfox jumps\n\nover the lazy dog\n";
+
+#[test]
+fn lines_fold_counts_lines() {
+    let text = TEXT.to_string();
+
+    assert_eq!(lines_fold().fold(&text, 0, |count, _| count + 1), 4);
+    assert_eq!(lines_fold().get_all(&text)[2], "");
+}
+
+#[test]
+fn words_fold_counts_words() {
+    let text = TEXT.to_string();
+
+    assert_eq!(words_fold().fold(&text, 0, |count, _| count + 1), 9);
+    assert_eq!(words_fold().get_all(&text)[..2], ["the", "quick"]);
+}
+
+#[test]
+fn lines_traversal_rewrites_lines_in_place() {
+    let mut text = "one\ntwo\nthree".to_string();
+
+    lines_traversal().modify_all(&mut text, |line| line.to_uppercase());
+
+    assert_eq!(text, "ONE\nTWO\nTHREE");
+    assert_eq!(
+        lines_traversal().get_all(&text),
+        vec!["ONE", "TWO", "THREE"]
+    );
+}
+
+#[test]
+fn words_fold_enumerate_numbers_words() {
+    let text = TEXT.to_string();
+
+    let numbered = words_fold().enumerate().get_all(&text);
+    assert_eq!(numbered.len(), 9);
+    assert_eq!(numbered[3], (3, "fox".to_string()));
+}
+
+#[test]
+fn scan_produces_running_sums() {
+    let values = mapped_fold(|v: &Vec<u32>| v.clone());
+
+    assert_eq!(
+        values.scan(&vec![3, 1, 4, 1, 5], 0, |sum, x| sum + x),
+        vec![3, 4, 8, 9, 14]
+    );
+    assert_eq!(values.scan(&vec![], 0, |sum, x| sum + x), vec![]);
+}
+
+#[test]
+fn scan_has_one_accumulation_per_focus() {
+    let text = TEXT.to_string();
+
+    let lengths = words_fold().scan(&text, 0, |total, word| total + word.len());
+    assert_eq!(lengths.len(), words_fold().count(&text));
+    assert_eq!(lengths.last(), Some(&35));
+}
+
+#[test]
+fn composed_folds_sum_nested_collections() {
+    let groups = vec![vec![vec![1, 2], vec![3]], vec![], vec![vec![4, 5, 6]]];
+    let items = mapped_fold(|groups: &Vec<Vec<Vec<u32>>>| groups.clone().into_iter().flatten());
+    let values = mapped_fold(|item: &Vec<u32>| item.clone());
+
+    let all = items.compose_with_fold(values);
+
+    assert_eq!(all.fold(&groups, 0, |sum, v| sum + v), 21);
+    assert_eq!(all.get_all(&groups), vec![1, 2, 3, 4, 5, 6]);
+}
+
+#[test]
+fn fold_composed_with_traversal_reads_through_both() {
+    let groups = vec![
+        vec!["ada".to_string(), "grace".to_string()],
+        vec!["alan".to_string()],
+    ];
+    let group_fold = mapped_fold(|groups: &Vec<Vec<_>>| groups.clone());
+
+    let names = group_fold.compose_with_traversal(each());
+
+    assert_eq!(names.get_all(&groups), ["ada", "grace", "alan"]);
+    assert_eq!(
+        names.find(&groups, |name| name.starts_with('g')),
+        Some("grace".to_string())
+    );
+}
+
+#[test]
+fn windows_fold_yields_overlapping_windows() {
+    let values = vec![1, 2, 3, 4];
+
+    assert_eq!(windows_fold(2).count(&values), 3);
+    assert_eq!(
+        windows_fold(3).get_all(&values),
+        vec![vec![1, 2, 3], vec![2, 3, 4]]
+    );
+}
+
+#[test]
+fn windows_fold_is_empty_when_size_exceeds_length() {
+    let values = vec![1, 2];
+
+    assert_eq!(windows_fold(2).count(&values), 1);
+    assert_eq!(windows_fold(3).get_all(&values), Vec::<Vec<i32>>::new());
+}
+
+#[test]
+fn reduce_combines_values_without_an_initial_accumulator() {
+    let values = mapped_fold(|v: &Vec<i32>| v.clone());
+
+    assert_eq!(values.reduce(&vec![1, 2, 3, 4], |a, b| a + b), Some(10));
+    assert_eq!(values.reduce(&vec![7], |a, b| a + b), Some(7));
+    assert_eq!(values.reduce(&vec![], |a, b| a + b), None);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Shipment {
+    Packed(Vec<u32>),
+    Pending,
+}
+
+#[test]
+fn prism_composed_with_fold_is_empty_for_the_wrong_variant() {
+    let packed = mapped_prism(
+        |s: &Shipment| match s {
+            Shipment::Packed(items) => Ok(items.clone()),
+            Shipment::Pending => Err(()),
+        },
+        |s, items| *s = Shipment::Packed(items),
+    );
+    let items = packed.compose_with_fold(mapped_fold(|items: &Vec<u32>| items.clone()));
+
+    assert_eq!(items.get_all(&Shipment::Packed(vec![4, 5])), vec![4, 5]);
+    assert_eq!(items.count(&Shipment::Pending), 0);
+}
+
+#[test]
+fn total_read_optics_compose_with_fold() {
+    let source = (vec![1, 2, 3], 'x');
+    let digits = || mapped_fold(|v: &Vec<u32>| v.clone());
+
+    let lens = mapped_lens(|s: &(Vec<u32>, char)| s.0.clone(), |s, v| s.0 = v);
+    let getter = mapped_getter(|s: &(Vec<u32>, char)| s.0.clone());
+    let iso = mapped_iso(|v: &Vec<u32>| v.clone(), |v: &Vec<u32>| v.clone());
+
+    assert_eq!(
+        lens.compose_with_fold(digits()).get_all(&source),
+        vec![1, 2, 3]
+    );
+    assert_eq!(
+        getter
+            .compose_with_fold(digits())
+            .fold(&source, 0, |a, b| a + b),
+        6
+    );
+    assert_eq!(iso.compose_with_fold(digits()).count(&source.0), 3);
+}
@@ -0,0 +1,198 @@
+use crate::test::helpers::{Record, records};
+use crate::{
+    DroppedError, HasGetter, HasTotalGetter, compare_by, deref_lift_getter, discriminant_getter,
+    err_into_getter, find_getter, mapped_getter, mapped_lens, string_byte_len_getter,
+    string_char_count_getter, weak_upgrade_getter,
+};
+use alloc::rc::Rc;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::sync::Arc;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Stats {
+    score: u32,
+}
+
+#[test]
+fn comparator_sorts_by_field() {
+    let mut records = records();
+    let score = mapped_getter(|r: &Record| r.score);
+
+    records.sort_by(score.comparator());
+
+    assert_eq!(
+        records.iter().map(|r| r.name).collect::<Vec<_>>(),
+        vec!["c", "a", "b", "d"]
+    );
+}
+
+#[test]
+fn compare_by_accepts_lenses() {
+    let mut records = records();
+    records.reverse();
+    let name_lens = mapped_lens(|r: &Record| r.name, |r, v| r.name = v);
+
+    records.sort_by(compare_by(name_lens));
+
+    assert_eq!(
+        records.iter().map(|r| r.name).collect::<Vec<_>>(),
+        vec!["a", "b", "c", "d"]
+    );
+}
+
+#[derive(Debug)]
+#[allow(dead_code)]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key(char),
+    Close,
+}
+
+#[test]
+fn discriminant_getter_compares_variants_only() {
+    let variant = discriminant_getter::<Event>();
+
+    assert_eq!(
+        variant.get(&Event::Click { x: 0, y: 0 }),
+        variant.get(&Event::Click { x: 5, y: -3 })
+    );
+    assert_eq!(variant.get(&Event::Key('a')), variant.get(&Event::Key('b')));
+    assert_ne!(variant.get(&Event::Key('a')), variant.get(&Event::Close));
+    assert_ne!(
+        variant.get(&Event::Click { x: 0, y: 0 }),
+        variant.get(&Event::Close)
+    );
+}
+
+#[test]
+fn weak_upgrade_getter_reads_while_alive() {
+    let node = Arc::new(vec![1, 2, 3]);
+    let parent = Arc::downgrade(&node);
+
+    assert_eq!(weak_upgrade_getter().try_get(&parent), Ok(vec![1, 2, 3]));
+}
+
+#[test]
+fn weak_upgrade_getter_fails_once_dropped() {
+    let node = Arc::new(5);
+    let parent = Arc::downgrade(&node);
+    let upgrade = weak_upgrade_getter();
+
+    drop(node);
+
+    assert_eq!(upgrade.try_get(&parent), Err(DroppedError));
+    assert_eq!(
+        upgrade.try_get(&std::sync::Weak::<u8>::new()),
+        Err(DroppedError)
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct IoError(&'static str);
+
+#[derive(Debug, PartialEq)]
+enum AppError {
+    Io(&'static str),
+}
+
+impl From<IoError> for AppError {
+    fn from(err: IoError) -> Self {
+        AppError::Io(err.0)
+    }
+}
+
+#[test]
+fn err_into_getter_converts_errors_and_skips_ok() {
+    let app_error = err_into_getter::<i32, IoError, AppError>();
+
+    assert_eq!(
+        app_error.try_get(&Err(IoError("disk full"))),
+        Ok(AppError::Io("disk full"))
+    );
+    assert_eq!(app_error.try_get(&Ok(3)), Err(()));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Directory {
+    records: Vec<Record>,
+}
+
+#[test]
+fn map_collect_reads_a_value_out_of_every_element() {
+    let entries = || mapped_getter(|d: &Directory| d.records.clone());
+    let directory = Directory { records: records() };
+
+    let names: Vec<String> = entries()
+        .map_collect(|r| r.name.to_string())
+        .get(&directory);
+    assert_eq!(names, vec!["a", "b", "c", "d"]);
+    assert_eq!(
+        entries()
+            .map_collect(|r| r.score)
+            .get(&Directory { records: vec![] }),
+        Vec::<u32>::new()
+    );
+}
+
+#[test]
+fn string_length_getters_count_chars_and_bytes() {
+    let chars = string_char_count_getter();
+    let bytes = string_byte_len_getter();
+
+    let ascii = "hello".to_string();
+    assert_eq!(chars.get(&ascii), 5);
+    assert_eq!(bytes.get(&ascii), 5);
+
+    let multi_byte = "héllo, 世界 🦀".to_string();
+    assert_eq!(chars.get(&multi_byte), 11);
+    assert_eq!(bytes.get(&multi_byte), 19);
+
+    assert_eq!(chars.get(&String::new()), 0);
+    assert_eq!(bytes.get(&String::new()), 0);
+}
+
+#[test]
+fn string_char_count_getter_composes_into_validator() {
+    let name_len = mapped_lens(
+        |r: &(String, u8)| r.0.clone(),
+        |r: &mut (String, u8), name| r.0 = name,
+    )
+    .compose_with_getter(string_char_count_getter());
+    let fits = |r: &(String, u8)| name_len.get(r) <= 4;
+
+    assert!(fits(&("Zoë".to_string(), 1)));
+    assert!(!fits(&("Zoëlla".to_string(), 1)));
+}
+
+#[test]
+fn find_getter_reads_first_matching_element() {
+    let first_active = mapped_lens(
+        |t: &(char, Vec<(&'static str, bool)>)| t.1.clone(),
+        |t, v| t.1 = v,
+    )
+    .compose_with_partial_getter(find_getter(|item: &(&'static str, bool)| item.1));
+
+    let source = (
+        't',
+        vec![("idle", false), ("first", true), ("second", true)],
+    );
+    assert_eq!(first_active.try_get(&source), Ok(("first", true)));
+
+    let inactive = ('t', vec![("idle", false), ("asleep", false)]);
+    assert_eq!(first_active.try_get(&inactive), Err(()));
+    assert_eq!(first_active.try_get(&('t', vec![])), Err(()));
+}
+
+#[test]
+fn deref_lift_getter_reads_through_shared_pointers() {
+    let score = deref_lift_getter(mapped_getter(|s: &Stats| s.score));
+    let shared = Rc::new(Stats { score: 3 });
+
+    assert_eq!(score.get(&shared), 3);
+    assert_eq!(shared.score, 3);
+
+    let score = deref_lift_getter(mapped_getter(|s: &Stats| s.score));
+    assert_eq!(score.get(&Arc::new(Stats { score: 4 })), 4);
+}
@@ -9,6 +9,24 @@ use syn::ItemMod;
 use std::process::Command;
 
 
+/// A record shared by the tests of optics over collections.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Record {
+  pub name: &'static str,
+  pub score: u32,
+  pub active: bool,
+}
+
+/// Four records in name order, with a tie between the highest scores.
+pub fn records() -> Vec<Record> {
+  vec![
+    Record { name: "a", score: 3, active: false },
+    Record { name: "b", score: 7, active: true },
+    Record { name: "c", score: 1, active: false },
+    Record { name: "d", score: 7, active: false },
+  ]
+}
+
 thread_local! {
   pub static CRATE_AST: Lazy<File> = Lazy::new(|| {
     let root_file = PathBuf::from("src/lib.rs");
@@ -46,7 +64,7 @@ fn expand_mods_in_file(path: &Path, base_dir: &Path) -> anyhow::Result<File> {
 }
 
 /// Recursively resolve external mods in a list of items.
-fn expand_mods_in_items(items: &mut Vec<Item>, base_dir: &Path) -> anyhow::Result<()> {
+fn expand_mods_in_items(items: &mut [Item], base_dir: &Path) -> anyhow::Result<()> {
   for item in items.iter_mut() {
     if let Item::Mod(mod_item) = item {
       expand_mod_item(mod_item, base_dir)?;
@@ -59,7 +77,7 @@ fn expand_mods_in_items(items: &mut Vec<Item>, base_dir: &Path) -> anyhow::Resul
 fn expand_mod_item(mod_item: &mut ItemMod, base_dir: &Path) -> anyhow::Result<()> {
   if mod_item.content.is_none() {
     let mod_name = mod_item.ident.to_string();
-    let mod_path_rs = base_dir.join(format!("{}.rs", mod_name));
+    let mod_path_rs = base_dir.join(format!("{mod_name}.rs"));
     let mod_path_modrs = base_dir.join(&mod_name).join("mod.rs");
 
     let mod_path = if mod_path_rs.exists() {
@@ -85,7 +103,7 @@ fn expand_mod_item(mod_item: &mut ItemMod, base_dir: &Path) -> anyhow::Result<()
     expand_mods_in_items(&mut sub_file.items, sub_base_dir)?;
 
     // Replace mod_item content with parsed items
-    mod_item.content = Some((Default::default(), sub_file.items));
+    mod_item.content = Some((syn::token::Brace::default(), sub_file.items));
   }
 
   // If already inlined, recurse into its content too
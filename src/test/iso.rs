@@ -0,0 +1,214 @@
+use crate::{
+    HasGetter, HasSetter, HasTotalGetter, HasTotalReverseGet, celsius_fahrenheit_iso, field_lens,
+    mapped_iso, mapped_lens, meters_feet_iso, ok_prism, option_default_iso, radians_degrees_iso,
+    rotate_iso, transpose_iso, unzip_iso,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+
+#[test]
+fn checked_iso_passes_for_lawful_iso() {
+    let add_one = mapped_iso(|x: &u32| x.wrapping_add(1), |y| y.wrapping_sub(1)).checked();
+
+    assert_eq!(add_one.get(&41), 42);
+    assert_eq!(add_one.get(&u32::MAX), 0);
+}
+
+#[test]
+#[cfg(any(debug_assertions, feature = "law-checks"))]
+#[should_panic(expected = "Iso round-trip law violated")]
+fn checked_iso_panics_for_broken_iso() {
+    let halve = mapped_iso(|x: &u32| x / 2, |y| y * 2).checked();
+
+    assert_eq!(halve.get(&4), 2);
+    let _ = halve.get(&3);
+}
+
+#[test]
+fn rotate_iso_round_trips() {
+    let rotate = rotate_iso::<char, 5>(7);
+    let original = ['a', 'b', 'c', 'd', 'e'];
+
+    let rotated = rotate.get(&original);
+    assert_eq!(rotated, ['c', 'd', 'e', 'a', 'b']);
+    assert_eq!(rotate.reverse_get(&rotated), original);
+}
+
+#[test]
+fn composed_rotations_sum_offsets() {
+    let original = [1, 2, 3, 4, 5, 6];
+    let composed = rotate_iso::<i32, 6>(2).compose_with_iso(rotate_iso(3));
+
+    assert_eq!(
+        composed.get(&original),
+        rotate_iso::<i32, 6>(5).get(&original)
+    );
+    assert_eq!(composed.reverse_get(&composed.get(&original)), original);
+}
+
+#[test]
+fn option_default_iso_collapses_default_and_none() {
+    let iso = option_default_iso::<i32>();
+
+    assert_eq!(iso.get(&None), 0);
+    assert_eq!(iso.get(&Some(0)), 0);
+    assert_eq!(iso.reverse_get(&0), None);
+    assert_eq!(iso.reverse_get(&iso.get(&Some(0))), None);
+
+    assert_eq!(iso.get(&Some(5)), 5);
+    assert_eq!(iso.reverse_get(&5), Some(5));
+}
+
+#[test]
+fn unzip_iso_round_trips() {
+    let unzip = unzip_iso::<u8, char>();
+    let pairs = vec![(1, 'a'), (2, 'b'), (3, 'c')];
+
+    let halves = unzip.get(&pairs);
+    assert_eq!(halves, (vec![1, 2, 3], vec!['a', 'b', 'c']));
+    assert_eq!(unzip.reverse_get(&halves), pairs);
+}
+
+#[test]
+fn unzip_iso_composes_with_tuple_lens() {
+    let firsts = unzip_iso::<u8, char>().compose_with_lens(mapped_lens(
+        |halves: &(Vec<u8>, Vec<char>)| halves.0.clone(),
+        |halves, v| halves.0 = v,
+    ));
+    let mut pairs = vec![(1, 'a'), (2, 'b')];
+
+    assert_eq!(firsts.get(&pairs), vec![1, 2]);
+
+    firsts.set(&mut pairs, vec![9, 8]);
+    assert_eq!(pairs, vec![(9, 'a'), (8, 'b')]);
+}
+
+#[test]
+fn bimap_post_composes_bijection_onto_focus() {
+    let offset = mapped_iso(|x: &i64| x + 10, |y| y - 10);
+    let doubled = offset.bimap(|y| y * 2, |z| z / 2);
+
+    assert_eq!(doubled.get(&5), 30);
+    assert_eq!(doubled.reverse_get(&30), 5);
+
+    let mut source = 0;
+    doubled.set(&mut source, 50);
+    assert_eq!(source, 15);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Settings {
+    volume: u8,
+    muted: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Profile(Settings);
+
+#[test]
+fn newtype_iso_composed_with_field_lens_is_a_lens() {
+    let unwrap = mapped_iso(|p: &Profile| p.0.clone(), |s: &Settings| Profile(s.clone()));
+    let volume = unwrap.compose_with_lens(field_lens(
+        |s: &Settings| &s.volume,
+        |s: &mut Settings| &mut s.volume,
+    ));
+    let mut profile = Profile(Settings {
+        volume: 3,
+        muted: false,
+    });
+
+    assert_eq!(volume.get(&profile), 3);
+
+    volume.set(&mut profile, 9);
+    assert_eq!(
+        profile,
+        Profile(Settings {
+            volume: 9,
+            muted: false
+        })
+    );
+}
+
+#[test]
+fn transpose_iso_round_trips_every_shape() {
+    let transpose = transpose_iso::<u8, char>();
+    let shapes = [
+        (None, Ok(None)),
+        (Some(Ok(1)), Ok(Some(1))),
+        (Some(Err('e')), Err('e')),
+    ];
+
+    for (source, focus) in shapes {
+        assert_eq!(transpose.get(&source), focus);
+        assert_eq!(transpose.reverse_get(&focus), source);
+    }
+}
+
+#[test]
+fn unit_isos_convert_known_values() {
+    const EPSILON: f64 = 1e-9;
+
+    assert!((celsius_fahrenheit_iso().get(&-40.0) + 40.0).abs() < EPSILON);
+    assert!((celsius_fahrenheit_iso().get(&37.0) - 98.6).abs() < EPSILON);
+    assert!((radians_degrees_iso().get(&core::f64::consts::FRAC_PI_2) - 90.0).abs() < EPSILON);
+    assert!((meters_feet_iso().get(&0.3048) - 1.0).abs() < EPSILON);
+    assert!((meters_feet_iso().reverse_get(&5280.0) - 1609.344).abs() < EPSILON);
+}
+
+#[test]
+fn unit_isos_round_trip_within_epsilon() {
+    const EPSILON: f64 = 1e-9;
+    let samples = [-273.15, -1.5, 0.0, 0.1, 1.0, 42.0, 1234.5678];
+
+    for value in samples {
+        let there = celsius_fahrenheit_iso().reverse_get(&celsius_fahrenheit_iso().get(&value));
+        assert!(
+            (there - value).abs() < EPSILON,
+            "celsius {value} -> {there}"
+        );
+        let there = radians_degrees_iso().reverse_get(&radians_degrees_iso().get(&value));
+        assert!(
+            (there - value).abs() < EPSILON,
+            "radians {value} -> {there}"
+        );
+        let there = meters_feet_iso().reverse_get(&meters_feet_iso().get(&value));
+        assert!((there - value).abs() < EPSILON, "meters {value} -> {there}");
+    }
+}
+
+#[test]
+fn iso_composed_with_prism_matches_newtype_payload() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Outcome(Result<u16, &'static str>);
+
+    let outcome = mapped_iso(|o: &Outcome| o.0, |r| Outcome(*r));
+    let success = outcome.compose_with_prism(ok_prism());
+
+    assert_eq!(success.try_get(&Outcome(Ok(200))), Ok(200));
+    assert_eq!(success.try_get(&Outcome(Err("timeout"))), Err(()));
+
+    let mut succeeded = Outcome(Ok(200));
+    success.set(&mut succeeded, 204);
+    assert_eq!(succeeded, Outcome(Ok(204)));
+
+    let mut failed = Outcome(Err("timeout"));
+    success.set(&mut failed, 201);
+    assert_eq!(failed, Outcome(Ok(201)));
+}
+
+#[test]
+fn then_reverse_composes_with_an_iso_defined_backwards() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Millis(u64);
+
+    let millis = mapped_iso(|m: &Millis| m.0, |m| Millis(*m));
+    let seconds_to_millis = mapped_iso(|s: &u64| s * 1000, |m| m / 1000);
+    let seconds = millis.then_reverse(seconds_to_millis);
+
+    assert_eq!(seconds.get(&Millis(5000)), 5);
+    assert_eq!(seconds.reverse_get(&7), Millis(7000));
+
+    let mut timeout = Millis(1000);
+    seconds.set(&mut timeout, 30);
+    assert_eq!(timeout, Millis(30_000));
+}
@@ -0,0 +1,649 @@
+use crate::{
+    HasOver, HasSetter, HasTotalGetter, HasTotalRefGetter, Lens, TupleLens, arc_mutex_lens,
+    bit_lens, box_deref_lens, cell_lens, clamped_lens, dedup_adjacent_lens, deque_back_lens,
+    deque_front_lens, deref_lift, field_lens, mapped_getter, mapped_iso, mapped_lens,
+    mapped_setter, ok_lens, pair_split_lens, read_write, result_ok_lens, sorted_view_lens,
+    split_at_lens, split_at_string_lens, tuple_0, tuple_1, tuple_lens, vec_len_lens,
+};
+use alloc::boxed::Box;
+use alloc::collections::VecDeque;
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::sync::{Arc, Mutex};
+
+struct User {
+    name: String,
+}
+
+#[test]
+fn lens_composed_with_getter_reads_through_both() {
+    let user = User {
+        name: "Ferris".to_string(),
+    };
+
+    let name_lens = mapped_lens(|u: &User| u.name.clone(), |u, v| u.name = v);
+    let name_length = mapped_getter(|n: &String| n.len());
+
+    let user_name_length = name_lens.compose_with_getter(name_length);
+
+    assert_eq!(user_name_length.get(&user), 6);
+}
+
+#[test]
+fn split_at_lens_splits_at_various_indices() {
+    let values = vec![1, 2, 3, 4];
+
+    assert_eq!(split_at_lens(0).get(&values), (vec![], vec![1, 2, 3, 4]));
+    assert_eq!(split_at_lens(2).get(&values), (vec![1, 2], vec![3, 4]));
+    assert_eq!(split_at_lens(4).get(&values), (vec![1, 2, 3, 4], vec![]));
+    assert_eq!(split_at_lens(10).get(&values), (vec![1, 2, 3, 4], vec![]));
+}
+
+#[test]
+fn split_at_lens_set_concatenates_halves() {
+    let halves = split_at_lens(2);
+    let mut values = vec![1, 2, 3, 4];
+
+    let read = halves.get(&values);
+    halves.set(&mut values, read);
+    assert_eq!(values, vec![1, 2, 3, 4]);
+
+    halves.set(&mut values, (vec![9], vec![8, 7]));
+    assert_eq!(values, vec![9, 8, 7]);
+    assert_eq!(halves.get(&values), (vec![9, 8], vec![7]));
+}
+
+#[test]
+fn split_at_string_lens_respects_char_boundaries() {
+    let text = "añb".to_string();
+
+    assert_eq!(
+        split_at_string_lens(0).get(&text),
+        (String::new(), "añb".to_string())
+    );
+    assert_eq!(
+        split_at_string_lens(2).get(&text),
+        ("a".to_string(), "ñb".to_string())
+    );
+    assert_eq!(
+        split_at_string_lens(3).get(&text),
+        ("añ".to_string(), "b".to_string())
+    );
+    assert_eq!(
+        split_at_string_lens(text.len()).get(&text),
+        (text.clone(), String::new())
+    );
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct Settings {
+    name: String,
+    retries: u8,
+    verbose: bool,
+}
+
+#[test]
+fn build_with_default_sets_single_field() {
+    let retries_lens = mapped_lens(|s: &Settings| s.retries, |s, v| s.retries = v);
+
+    let settings = retries_lens.build_with_default(3);
+
+    assert_eq!(
+        settings,
+        Settings {
+            name: String::new(),
+            retries: 3,
+            verbose: false,
+        }
+    );
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+}
+
+#[test]
+fn box_deref_lens_composes_with_field_lens() {
+    let mut boxed = Box::new(Node {
+        value: 1,
+        next: Some(Box::new(Node {
+            value: 2,
+            next: None,
+        })),
+    });
+
+    let value_lens = mapped_lens(|n: &Node| n.value, |n, v| n.value = v);
+    let boxed_value = box_deref_lens().compose_with_lens(value_lens);
+
+    assert_eq!(boxed_value.get(&boxed), 1);
+
+    boxed_value.set(&mut boxed, 10);
+    assert_eq!(boxed.value, 10);
+    assert_eq!(boxed.next.as_ref().map(|n| n.value), Some(2));
+}
+
+#[test]
+fn box_deref_lens_borrows_without_cloning() {
+    let mut boxed = Box::new(Node {
+        value: 1,
+        next: None,
+    });
+    let deref = box_deref_lens();
+
+    assert!(core::ptr::eq(deref.get_ref(&boxed), &raw const *boxed));
+
+    deref.get_mut(&mut boxed).value = 5;
+    assert_eq!(boxed.value, 5);
+}
+
+#[test]
+fn named_optics_compare_equal() {
+    assert_eq!(tuple_0::<u8, char>(), tuple_0::<u8, char>());
+    assert_eq!(tuple_1::<u8, char>(), tuple_1::<u8, char>());
+    assert_eq!(
+        alloc::format!("{:?}", tuple_0::<u8, char>()),
+        "LensImpl(tuple_0)"
+    );
+}
+
+#[test]
+fn tuple_lenses_focus_elements() {
+    let mut pair = (1, 'a');
+
+    tuple_0().set(&mut pair, 2);
+    tuple_1().set(&mut pair, 'b');
+
+    assert_eq!(pair, (2, 'b'));
+    assert_eq!(tuple_0().get(&pair), 2);
+}
+
+#[test]
+fn tuple_lens_focuses_middle_of_triple() {
+    let mut triple = (1, 'a', 2.5);
+    let middle = tuple_lens::<1, _>();
+
+    assert_eq!(middle.get(&triple), 'a');
+
+    middle.set(&mut triple, 'b');
+    assert_eq!(triple, (1, 'b', 2.5));
+    assert_eq!(
+        alloc::format!("{:?}", tuple_lens::<1, (u8, char, f64)>()),
+        "LensImpl(tuple_lens::<1>)"
+    );
+}
+
+fn invert_last<T: TupleLens<2, Field = u8>>(source: &mut T) {
+    let last = tuple_lens::<2, T>();
+    last.set(source, u8::MAX - last.get(source));
+}
+
+#[test]
+fn tuple_lens_is_usable_in_generic_code() {
+    let mut triple = ('a', "b", 1u8);
+    let mut quad = (0, 0, 2u8, 0);
+
+    invert_last(&mut triple);
+    invert_last(&mut quad);
+
+    assert_eq!(triple.2, 254);
+    assert_eq!(quad.2, 253);
+}
+
+#[test]
+fn vec_len_lens_grows_and_shrinks() {
+    let len = vec_len_lens();
+    let mut buffer = vec![String::from("a"), String::from("b")];
+
+    len.set(&mut buffer, 4);
+    assert_eq!(buffer, ["a", "b", "", ""]);
+    assert_eq!(len.get(&buffer), 4);
+
+    len.set(&mut buffer, 1);
+    assert_eq!(buffer, ["a"]);
+
+    len.set(&mut buffer, 2);
+    assert_eq!(buffer, ["a", ""]);
+}
+
+#[test]
+fn map_iso_applies_iso_in_both_directions() {
+    let count = mapped_lens(|p: &(u32, char)| p.0, |p, v| p.0 = v);
+    let as_string = count.map_iso(mapped_iso(|v: &u32| v.to_string(), |s| s.parse().unwrap()));
+
+    let mut pair = (12, 'x');
+    assert_eq!(as_string.get(&pair), "12");
+
+    as_string.set(&mut pair, "40".to_string());
+    assert_eq!(pair, (40, 'x'));
+}
+
+#[test]
+fn pair_split_lens_reads_parts_and_rebuilds_source() {
+    #[derive(Debug, PartialEq)]
+    struct Span {
+        start: usize,
+        len: usize,
+    }
+
+    let parts = pair_split_lens(
+        |s: &Span| s.start,
+        |s: &Span| s.start + s.len,
+        |start, end| Span {
+            start,
+            len: end - start,
+        },
+    );
+    let mut span = Span { start: 2, len: 3 };
+
+    assert_eq!(parts.get(&span), (2, 5));
+
+    parts.set(&mut span, (4, 10));
+    assert_eq!(span, Span { start: 4, len: 6 });
+}
+
+#[test]
+fn bit_lens_tests_and_toggles_single_bits() {
+    let mut flags: u32 = 0b1010;
+
+    assert!(bit_lens(1).get(&flags));
+    assert!(!bit_lens(2).get(&flags));
+    assert!(!bit_lens(31).get(&flags));
+
+    bit_lens(2).set(&mut flags, true);
+    assert_eq!(flags, 0b1110);
+
+    bit_lens(3).set(&mut flags, false);
+    assert_eq!(flags, 0b0110);
+
+    bit_lens(31).set(&mut flags, true);
+    assert_eq!(flags, 0x8000_0006);
+
+    bit_lens(1).set(&mut flags, true);
+    assert_eq!(flags, 0x8000_0006);
+}
+
+#[test]
+#[should_panic(expected = "bit_lens requires n < 32")]
+fn bit_lens_rejects_out_of_range_bit() {
+    let _ = bit_lens(32);
+}
+
+struct Playlist {
+    tracks: Vec<u32>,
+}
+
+#[test]
+fn field_lens_sets_in_place_and_borrows_without_cloning() {
+    let tracks = field_lens(|p: &Playlist| &p.tracks, |p: &mut Playlist| &mut p.tracks);
+    let mut playlist = Playlist {
+        tracks: vec![1, 2, 3],
+    };
+
+    let borrowed = tracks.get_ref(&playlist);
+    assert_eq!(borrowed.as_ptr(), playlist.tracks.as_ptr());
+
+    tracks.set(&mut playlist, vec![4, 5]);
+    assert_eq!(playlist.tracks, vec![4, 5]);
+
+    tracks.get_mut(&mut playlist).push(6);
+    assert_eq!(tracks.get(&playlist), vec![4, 5, 6]);
+}
+
+#[test]
+fn result_ok_lens_reads_ok_as_some_and_err_as_none() {
+    let ok = result_ok_lens(|| "default".to_string());
+
+    assert_eq!(ok.get(&Ok::<u8, String>(4)), Some(4));
+    assert_eq!(ok.get(&Err::<u8, String>("boom".to_string())), None);
+}
+
+#[test]
+fn result_ok_lens_writes_some_as_ok_and_none_as_default_err() {
+    let ok = result_ok_lens(|| "default".to_string());
+    let mut value: Result<u8, String> = Err("boom".to_string());
+
+    ok.set(&mut value, Some(9));
+    assert_eq!(value, Ok(9));
+
+    ok.set(&mut value, None);
+    assert_eq!(value, Err("default".to_string()));
+}
+
+#[test]
+fn clamped_lens_clamps_writes_into_range() {
+    let volume = clamped_lens(mapped_lens(|v: &i32| *v, |v, x| *v = x), 0, 10);
+    let mut level = 5;
+
+    volume.set(&mut level, -3);
+    assert_eq!(level, 0);
+
+    volume.set(&mut level, 11);
+    assert_eq!(level, 10);
+
+    volume.set(&mut level, 7);
+    assert_eq!(level, 7);
+}
+
+#[test]
+fn clamped_lens_reads_out_of_range_values_unchanged() {
+    let volume = clamped_lens(mapped_lens(|v: &i32| *v, |v, x| *v = x), 0, 10);
+
+    assert_eq!(volume.get(&42), 42);
+}
+
+#[test]
+#[should_panic(expected = "min <= max")]
+fn clamped_lens_rejects_empty_range() {
+    let _ = clamped_lens(mapped_lens(|v: &i32| *v, |v, x| *v = x), 10, 0);
+}
+
+#[test]
+fn try_modify_with_leaves_source_unchanged_on_failure() {
+    let name = mapped_lens(|u: &User| u.name.clone(), |u, n| u.name = n);
+    let mut user = User {
+        name: "ada".to_string(),
+    };
+
+    let result: Result<(), &str> = name.try_modify_with(&mut user, |n| {
+        if n.is_empty() {
+            Err("empty")
+        } else {
+            Ok(n.to_uppercase())
+        }
+    });
+    assert_eq!(result, Ok(()));
+    assert_eq!(user.name, "ADA");
+
+    assert_eq!(
+        name.try_modify_with(&mut user, |_| Err::<String, _>("rejected")),
+        Err("rejected")
+    );
+    assert_eq!(user.name, "ADA");
+}
+
+#[test]
+fn deque_front_lens_pushes_replaces_and_pops() {
+    let front = deque_front_lens();
+    let mut queue = VecDeque::new();
+
+    assert_eq!(front.get(&queue), None);
+
+    front.set(&mut queue, Some(1));
+    assert_eq!(queue, [1]);
+
+    queue.push_back(2);
+    front.set(&mut queue, Some(5));
+    assert_eq!(queue, [5, 2]);
+    assert_eq!(front.get(&queue), Some(5));
+
+    front.set(&mut queue, None);
+    assert_eq!(queue, [2]);
+    front.set(&mut queue, None);
+    front.set(&mut queue, None);
+    assert!(queue.is_empty());
+}
+
+#[test]
+fn deque_back_lens_pushes_replaces_and_pops() {
+    let back = deque_back_lens();
+    let mut queue = VecDeque::new();
+
+    assert_eq!(back.get(&queue), None);
+
+    back.set(&mut queue, Some(1));
+    assert_eq!(queue, [1]);
+
+    queue.push_front(0);
+    back.set(&mut queue, Some(5));
+    assert_eq!(queue, [0, 5]);
+    assert_eq!(back.get(&queue), Some(5));
+
+    back.set(&mut queue, None);
+    assert_eq!(queue, [0]);
+    back.set(&mut queue, None);
+    back.set(&mut queue, None);
+    assert!(queue.is_empty());
+}
+
+#[derive(Debug)]
+struct Stats {
+    hits: core::cell::Cell<u32>,
+}
+
+#[test]
+fn cell_lens_reads_through_shared_references_and_writes_in_place() {
+    let hits =
+        field_lens(|s: &Stats| &s.hits, |s: &mut Stats| &mut s.hits).compose_with_lens(cell_lens());
+    let mut stats = Stats {
+        hits: core::cell::Cell::new(0),
+    };
+
+    let shared = &stats;
+    shared.hits.set(shared.hits.get() + 1);
+    assert_eq!(hits.get(shared), 1);
+
+    hits.set(&mut stats, 5);
+    assert_eq!(stats.hits.get(), 5);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Cart {
+    prices: Vec<u32>,
+    discount: u32,
+}
+
+#[test]
+fn read_write_computes_on_get_and_stores_on_set() {
+    let total = read_write(
+        mapped_getter(|c: &Cart| c.prices.iter().sum::<u32>() - c.discount),
+        mapped_setter(|c: &mut Cart, total: u32| {
+            let full: u32 = c.prices.iter().sum();
+            c.discount = full.saturating_sub(total);
+        }),
+    );
+    let mut cart = Cart {
+        prices: vec![10, 20, 30],
+        discount: 0,
+    };
+
+    assert_eq!(total.get(&cart), 60);
+
+    total.set(&mut cart, 45);
+    assert_eq!(cart.discount, 15);
+    assert_eq!(total.get(&cart), 45);
+
+    let before = cart.clone();
+    let current = total.get(&cart);
+    total.set(&mut cart, current);
+    assert_eq!(cart, before);
+}
+
+#[test]
+fn ok_lens_setting_none_preserves_the_error_payload() {
+    let ok = ok_lens();
+    let mut value: Result<u32, String> = Err("disk full".to_string());
+
+    assert_eq!(ok.get(&value), None);
+    ok.set(&mut value, None);
+    assert_eq!(value, Err("disk full".to_string()));
+
+    ok.set(&mut value, Some(4));
+    assert_eq!(value, Ok(4));
+
+    ok.set(&mut value, None);
+    assert_eq!(value, Ok(4));
+}
+
+std::thread_local! {
+    static CLONES: core::cell::Cell<usize> = const { core::cell::Cell::new(0) };
+}
+
+/// A container counting how many times it is cloned, i.e. read by value.
+#[derive(Debug, PartialEq)]
+struct Counted<T>(T);
+
+impl<T: Clone> Clone for Counted<T> {
+    fn clone(&self) -> Self {
+        CLONES.with(|c| c.set(c.get() + 1));
+        Counted(self.0.clone())
+    }
+}
+
+type Level3 = Counted<u32>;
+type Level2 = Counted<Level3>;
+type Level1 = Counted<Level2>;
+
+#[test]
+fn ref_lens_chain_reads_and_modifies_without_cloning_containers() {
+    let l1 = || {
+        field_lens(
+            |s: &Counted<Level1>| &s.0,
+            |s: &mut Counted<Level1>| &mut s.0,
+        )
+    };
+    let l2 = || field_lens(|s: &Level1| &s.0, |s: &mut Level1| &mut s.0);
+    let l3 = || field_lens(|s: &Level2| &s.0, |s: &mut Level2| &mut s.0);
+    let mut root = Counted(Counted(Counted(Counted(1))));
+
+    let by_ref = l1().compose_with_ref_lens(l2()).compose_with_ref_lens(l3());
+    CLONES.with(|c| c.set(0));
+
+    by_ref.get_mut(&mut root).0 += 1;
+    assert_eq!(by_ref.get_ref(&root).0, 2);
+    by_ref.set(&mut root, Counted(5));
+    assert_eq!(((root.0).0).0, Counted(5));
+    assert_eq!(by_ref.get(&root), Counted(5));
+    assert_eq!(CLONES.with(core::cell::Cell::get), 1);
+
+    let by_value = l1().compose_with_lens(l2()).compose_with_lens(l3());
+    CLONES.with(|c| c.set(0));
+    by_value.set(&mut root, Counted(6));
+    assert_eq!(((root.0).0).0, Counted(6));
+    assert!(CLONES.with(core::cell::Cell::get) > 1);
+}
+
+#[test]
+fn deref_lift_reads_and_writes_a_field_through_a_box() {
+    let value = deref_lift(field_lens(|n: &Node| &n.value, |n: &mut Node| &mut n.value));
+    let mut node = Box::new(Node {
+        value: 3,
+        next: Some(Box::new(Node {
+            value: 4,
+            next: None,
+        })),
+    });
+    let allocation: *const Node = &raw const *node;
+
+    assert_eq!(value.get(&node), 3);
+
+    value.set(&mut node, 8);
+    assert_eq!(node.value, 8);
+    assert_eq!(node.next.as_ref().map(|n| n.value), Some(4));
+    assert!(core::ptr::eq(allocation, &raw const *node));
+}
+
+#[test]
+fn sorted_view_lens_reads_sorted_and_stores_as_given() {
+    let sorted = sorted_view_lens();
+    let mut tags = vec!["beta", "alpha", "gamma", "alpha"];
+
+    assert_eq!(sorted.get(&tags), vec!["alpha", "alpha", "beta", "gamma"]);
+    assert_eq!(tags, vec!["beta", "alpha", "gamma", "alpha"]);
+
+    sorted.set(&mut tags, vec!["zeta", "eta"]);
+    assert_eq!(tags, vec!["zeta", "eta"]);
+    assert_eq!(sorted.get(&tags), vec!["eta", "zeta"]);
+
+    sorted.set(&mut tags, vec![]);
+    assert_eq!(sorted.get(&tags), Vec::<&str>::new());
+}
+
+#[test]
+fn modify_at_transforms_in_bounds_element_only() {
+    let items = mapped_lens(|s: &(char, Vec<String>)| s.1.clone(), |s, v| s.1 = v);
+    let mut source = ('x', vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+
+    items.modify_at(&mut source, 0, |s| s.to_uppercase());
+    items.modify_at(&mut source, 2, |s| s + "!");
+    assert_eq!(source.1, vec!["A", "b", "c!"]);
+
+    items.modify_at(&mut source, 3, |_| {
+        unreachable!("out of bounds index was modified")
+    });
+    assert_eq!(source.1, vec!["A", "b", "c!"]);
+
+    let mut empty = ('y', Vec::new());
+    items.modify_at(&mut empty, 0, |s| s);
+    assert!(empty.1.is_empty());
+}
+
+#[test]
+fn arc_mutex_lens_shares_reads_and_writes_between_handles() {
+    let shared = arc_mutex_lens();
+    let mut writer = Arc::new(Mutex::new(vec![1]));
+    let reader = Arc::clone(&writer);
+
+    shared.set(&mut writer, vec![1, 2]);
+    assert_eq!(shared.get(&reader), vec![1, 2]);
+
+    let handle = {
+        let mut remote = Arc::clone(&reader);
+        std::thread::spawn(move || {
+            let mut values = shared.get(&remote);
+            values.push(3);
+            shared.set(&mut remote, values);
+        })
+    };
+    handle.join().unwrap();
+
+    assert_eq!(arc_mutex_lens().get(&writer), vec![1, 2, 3]);
+    assert_eq!(*reader.lock().unwrap(), vec![1, 2, 3]);
+}
+
+#[test]
+fn arc_mutex_lens_reads_and_writes_through_poisoned_mutex() {
+    let shared = arc_mutex_lens();
+    let mut state = Arc::new(Mutex::new(5));
+
+    let poisoner = Arc::clone(&state);
+    let _ = std::thread::spawn(move || {
+        let _guard = poisoner.lock().unwrap();
+        panic!("poisoning the mutex");
+    })
+    .join();
+    assert!(state.is_poisoned());
+
+    assert_eq!(shared.get(&state), 5);
+    shared.set(&mut state, 6);
+    assert_eq!(shared.get(&state), 6);
+}
+
+#[test]
+fn dedup_adjacent_lens_set_matches_vec_dedup() {
+    let runs = vec![1, 1, 2, 3, 3, 3, 1, 4, 4];
+    let mut expected = runs.clone();
+    expected.dedup();
+
+    let mut values = vec![9];
+    dedup_adjacent_lens().set(&mut values, runs);
+
+    assert_eq!(values, expected);
+    assert_eq!(values, vec![1, 2, 3, 1, 4]);
+}
+
+#[test]
+fn dedup_adjacent_lens_over_dedups_in_place() {
+    let mut values = vec![1, 1, 2, 2, 2, 1];
+
+    dedup_adjacent_lens().over(&mut values, |v| v);
+    assert_eq!(values, vec![1, 2, 1]);
+
+    dedup_adjacent_lens().over(&mut values, |mut v| {
+        v.push(1);
+        v
+    });
+    assert_eq!(values, vec![1, 2, 1]);
+}
@@ -1,5 +1,14 @@
 pub mod helpers;
 mod code_quality;
+mod compose;
+mod fallible_iso;
+mod fold;
+mod getter;
+mod iso;
+mod lens;
+mod prism;
+mod setter;
+mod traversal;
 
 use crate::HasGetter;
 use crate::HasSetter;
@@ -0,0 +1,703 @@
+use crate::{
+    Compose, CompositeError, HasCanSet, HasGetter, HasRefGetter, HasReverseGet, HasSetter,
+    HasTotalReverseGet, PathError, PendingError, SubstrError, field_prism, leading_int_prism,
+    map_entry_eq, mapped_fallible_iso, mapped_iso, mapped_lens, mapped_prism, nonnull_prism,
+    ok_prism, poll_ready_prism, prism_opt, some_prism, substr_prism, try_optic, tuple_lens,
+    variant_prism, vec_index_clamped,
+};
+use alloc::string::{String, ToString};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cell::Cell;
+use core::task::Poll;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, PartialEq)]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+#[test]
+fn prism_opt_extracts_some_payload() {
+    let some = prism_opt(|o: &Option<u32>| *o, Some);
+
+    assert_eq!(some.try_get(&Some(3)), Ok(3));
+    assert_eq!(some.try_get(&None), Err(()));
+
+    let mut value = None;
+    some.set(&mut value, 5);
+    assert_eq!(value, Some(5));
+}
+
+#[test]
+fn prism_opt_reconstructs_variant() {
+    let circle = prism_opt(
+        |s: &Shape| {
+            if let Shape::Circle(r) = s {
+                Some(*r)
+            } else {
+                None
+            }
+        },
+        Shape::Circle,
+    );
+
+    assert_eq!(circle.try_get(&Shape::Circle(1.5)), Ok(1.5));
+    assert_eq!(circle.try_get(&Shape::Square(2.0)), Err(()));
+
+    let mut shape = Shape::Square(2.0);
+    circle.set(&mut shape, 3.0);
+    assert_eq!(shape, Shape::Circle(3.0));
+}
+
+#[test]
+fn poll_ready_prism_focuses_ready_payload() {
+    let ready = poll_ready_prism::<u32>();
+
+    assert_eq!(ready.try_get(&Poll::Ready(7)), Ok(7));
+    assert_eq!(ready.try_get(&Poll::Pending), Err(PendingError));
+}
+
+#[test]
+fn poll_ready_prism_reconstructs_ready() {
+    let ready = poll_ready_prism::<u32>();
+
+    let mut poll = Poll::Pending;
+    ready.set(&mut poll, 1);
+    assert_eq!(poll, Poll::Ready(1));
+
+    assert_eq!(ready.reverse_get(&2), Poll::Ready(2));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Message {
+    Text(String),
+    Ping,
+}
+
+#[test]
+fn try_optic_distinguishes_failing_stage() {
+    let first = mapped_prism(
+        |v: &Vec<Message>| v.first().cloned().ok_or("empty"),
+        |v, m| {
+            if let Some(x) = v.first_mut() {
+                *x = m;
+            }
+        },
+    );
+    let text = prism_opt(
+        |m: &Message| {
+            if let Message::Text(t) = m {
+                Some(t.clone())
+            } else {
+                None
+            }
+        },
+        Message::Text,
+    );
+    let number = mapped_prism(|t: &String| t.parse::<i32>(), |t, n| *t = n.to_string());
+
+    let first_number = try_optic!(first, text, number);
+
+    assert_eq!(
+        first_number.try_get(&vec![Message::Text("12".to_string())]),
+        Ok(12)
+    );
+    assert_eq!(
+        first_number.try_get(&vec![]),
+        Err(CompositeError::Stage1("empty"))
+    );
+    assert_eq!(
+        first_number.try_get(&vec![Message::Ping]),
+        Err(CompositeError::Stage2(()))
+    );
+    assert!(matches!(
+        first_number.try_get(&vec![Message::Text("twelve".to_string())]),
+        Err(CompositeError::Stage3(_))
+    ));
+}
+
+#[test]
+fn try_optic_accepts_total_optics_in_chain() {
+    let some = prism_opt(|o: &Option<u8>| *o, Some);
+    let doubled = mapped_iso(
+        |x: &u8| u16::from(*x) * 2,
+        |y| u8::try_from(y / 2).unwrap_or(u8::MAX),
+    );
+
+    let some_doubled = try_optic!(some, doubled);
+
+    assert_eq!(some_doubled.try_get(&Some(4)), Ok(8));
+    assert_eq!(some_doubled.try_get(&None), Err(CompositeError::Stage1(())));
+}
+
+#[derive(Debug, PartialEq)]
+enum FieldError {
+    WrongVariant,
+    Unparseable(core::num::ParseIntError),
+}
+
+impl From<()> for FieldError {
+    fn from((): ()) -> Self {
+        FieldError::WrongVariant
+    }
+}
+
+impl From<core::num::ParseIntError> for FieldError {
+    fn from(e: core::num::ParseIntError) -> Self {
+        FieldError::Unparseable(e)
+    }
+}
+
+#[test]
+fn prism_composed_with_fallible_iso_merges_errors() {
+    let text = prism_opt(
+        |m: &Message| {
+            if let Message::Text(t) = m {
+                Some(t.clone())
+            } else {
+                None
+            }
+        },
+        Message::Text,
+    );
+    let parsed = mapped_fallible_iso(
+        |t: &String| t.parse::<i32>(),
+        |n: &i32| Ok::<_, core::num::ParseIntError>(n.to_string()),
+    );
+
+    let number = text.compose_with_fallible_iso::<FieldError, _, _>(parsed);
+
+    assert_eq!(number.try_get(&Message::Text("42".to_string())), Ok(42));
+    assert_eq!(
+        number.try_get(&Message::Ping),
+        Err(FieldError::WrongVariant)
+    );
+    assert!(matches!(
+        number.try_get(&Message::Text("x".to_string())),
+        Err(FieldError::Unparseable(_))
+    ));
+
+    let mut message = Message::Text("1".to_string());
+    number.set(&mut message, 7);
+    assert_eq!(message, Message::Text("7".to_string()));
+}
+
+#[test]
+fn some_prism_focuses_some_and_compares_equal() {
+    let mut value = None;
+
+    assert_eq!(some_prism().try_get(&value), Err(()));
+    some_prism().set(&mut value, 'x');
+    assert_eq!(some_prism().try_get(&value), Ok('x'));
+
+    assert_eq!(some_prism::<char>(), some_prism::<char>());
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Circle {
+    radius: u32,
+    filled: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Figure {
+    Circle(Circle),
+    Empty,
+}
+
+#[test]
+fn prism_composed_with_lens_reads_and_writes_payload_field() {
+    let circle = prism_opt(
+        |f: &Figure| {
+            if let Figure::Circle(c) = f {
+                Some(c.clone())
+            } else {
+                None
+            }
+        },
+        Figure::Circle,
+    );
+    let radius = mapped_lens(|c: &Circle| c.radius, |c, r| c.radius = r);
+    let circle_radius = circle.compose_with_lens(radius);
+
+    let mut matched = Figure::Circle(Circle {
+        radius: 2,
+        filled: true,
+    });
+    assert_eq!(circle_radius.try_get(&matched), Ok(2));
+
+    circle_radius.set(&mut matched, 5);
+    assert_eq!(
+        matched,
+        Figure::Circle(Circle {
+            radius: 5,
+            filled: true
+        })
+    );
+
+    let mut unmatched = Figure::Empty;
+    assert_eq!(circle_radius.try_get(&unmatched), Err(()));
+
+    circle_radius.set(&mut unmatched, 5);
+    assert_eq!(unmatched, Figure::Empty);
+}
+
+#[test]
+fn map_entry_eq_reads_only_matching_values() {
+    let map = HashMap::from([("a", 1), ("b", 2)]);
+
+    assert_eq!(map_entry_eq("a", 1).try_get(&map), Ok(1));
+    assert_eq!(map_entry_eq("b", 1).try_get(&map), Err(()));
+    assert_eq!(map_entry_eq("c", 1).try_get(&map), Err(()));
+}
+
+#[test]
+fn map_entry_eq_set_is_compare_and_set() {
+    let mut map = HashMap::from([("a", 1)]);
+    let at_one = map_entry_eq("a", 1);
+
+    at_one.set(&mut map, 5);
+    assert_eq!(map["a"], 5);
+
+    at_one.set(&mut map, 9);
+    assert_eq!(map["a"], 5);
+
+    map_entry_eq("missing", 1).set(&mut map, 7);
+    assert_eq!(map.get("missing"), None);
+}
+
+#[cfg(feature = "either")]
+mod either {
+    use crate::{HasGetter, HasSetter, HasTotalReverseGet, left_prism, right_prism};
+    use either::Either;
+
+    #[test]
+    fn left_and_right_prisms_match_their_branch() {
+        let left: Either<u8, char> = Either::Left(1);
+        let right: Either<u8, char> = Either::Right('r');
+
+        assert_eq!(left_prism().try_get(&left), Ok(1));
+        assert_eq!(left_prism().try_get(&right), Err(()));
+        assert_eq!(right_prism().try_get(&right), Ok('r'));
+        assert_eq!(right_prism().try_get(&left), Err(()));
+    }
+
+    #[test]
+    fn left_and_right_prisms_reconstruct_their_branch() {
+        let mut value: Either<u8, char> = Either::Left(1);
+
+        right_prism().set(&mut value, 'z');
+        assert_eq!(value, Either::Right('z'));
+
+        left_prism().set(&mut value, 7);
+        assert_eq!(value, Either::Left(7));
+    }
+
+    #[test]
+    fn left_and_right_prisms_reverse_get_their_branch() {
+        assert_eq!(left_prism::<u8, char>().reverse_get(&4), Either::Left(4));
+        assert_eq!(
+            right_prism::<u8, char>().reverse_get(&'q'),
+            Either::Right('q')
+        );
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Envelope {
+    message: Option<Message>,
+}
+
+#[test]
+fn labeled_three_stage_chain_reports_failing_stage() {
+    let message = mapped_prism(
+        |e: &Envelope| e.message.clone().ok_or("no message"),
+        |e, m| e.message = Some(m),
+    )
+    .labeled::<String>("message");
+    let text = mapped_prism(
+        |m: &Message| match m {
+            Message::Text(t) => Ok(t.clone()),
+            Message::Ping => Err("not a text message"),
+        },
+        |m, t| *m = Message::Text(t),
+    )
+    .labeled("message.text");
+    let number = mapped_prism(
+        |t: &String| t.parse::<u32>().map_err(|e| e.to_string()),
+        |t, n: u32| *t = n.to_string(),
+    )
+    .labeled("message.text.parse");
+
+    let chain = message.compose(text).compose(number);
+
+    let ok = Envelope {
+        message: Some(Message::Text("12".to_string())),
+    };
+    assert_eq!(chain.try_get(&ok), Ok(12));
+
+    let missing = Envelope { message: None };
+    assert_eq!(chain.try_get(&missing).map_err(|e| e.stage), Err("message"));
+
+    let ping = Envelope {
+        message: Some(Message::Ping),
+    };
+    assert_eq!(
+        chain.try_get(&ping),
+        Err(PathError {
+            stage: "message.text",
+            source: "not a text message".to_string()
+        })
+    );
+
+    let garbage = Envelope {
+        message: Some(Message::Text("twelve".to_string())),
+    };
+    let error = chain.try_get(&garbage).unwrap_err();
+    assert_eq!(error.stage, "message.text.parse");
+    assert_eq!(
+        error.to_string(),
+        "failed at stage 'message.text.parse': invalid digit found in string"
+    );
+}
+
+#[test]
+fn labeled_prism_still_sets_through() {
+    let mut value = Some(1);
+    let some = some_prism().labeled::<()>("some");
+
+    some.set(&mut value, 5);
+    assert_eq!(some.try_get(&value), Ok(5));
+}
+
+#[derive(Debug, PartialEq)]
+enum ModifyError {
+    Missing,
+    Overflow,
+}
+
+impl From<()> for ModifyError {
+    fn from((): ()) -> Self {
+        ModifyError::Missing
+    }
+}
+
+#[test]
+fn prism_try_modify_with_merges_optic_and_closure_errors() {
+    let double = |x: u8| x.checked_mul(2).ok_or(ModifyError::Overflow);
+
+    let mut value = Some(10);
+    assert_eq!(some_prism().try_modify_with(&mut value, double), Ok(()));
+    assert_eq!(value, Some(20));
+
+    let mut big = Some(200);
+    assert_eq!(
+        some_prism().try_modify_with(&mut big, double),
+        Err(ModifyError::Overflow)
+    );
+    assert_eq!(big, Some(200));
+
+    let mut missing: Option<u8> = None;
+    assert_eq!(
+        some_prism().try_modify_with(&mut missing, double),
+        Err(ModifyError::Missing)
+    );
+    assert_eq!(missing, None);
+}
+
+#[test]
+fn vec_index_clamped_reads_and_sets_last_element_when_out_of_range() {
+    let mut items = vec![1, 2, 3];
+    let cursor = vec_index_clamped(10);
+
+    assert_eq!(cursor.try_get(&items), Ok(3));
+    cursor.set(&mut items, 30);
+    assert_eq!(items, [1, 2, 30]);
+
+    assert_eq!(vec_index_clamped(1).try_get(&items), Ok(2));
+}
+
+#[test]
+fn vec_index_clamped_fails_only_on_empty() {
+    let mut empty: Vec<u8> = Vec::new();
+    let cursor = vec_index_clamped(0);
+
+    assert_eq!(cursor.try_get(&empty), Err(()));
+    cursor.set(&mut empty, 1);
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn try_reverse_get_many_builds_options_from_values() {
+    let built = some_prism().try_reverse_get_many(&[1, 2, 3]);
+
+    assert_eq!(built, [Ok(Some(1)), Ok(Some(2)), Ok(Some(3))]);
+}
+
+#[test]
+fn try_reverse_get_many_keeps_failures_per_value() {
+    let parse = mapped_fallible_iso(
+        |s: &String| s.parse::<i32>().map_err(|_| ()),
+        |v: &i32| if *v < 0 { Err(()) } else { Ok(v.to_string()) },
+    );
+
+    assert_eq!(
+        parse.try_reverse_get_many(&[4, -1, 0]),
+        [Ok("4".to_string()), Err(()), Ok("0".to_string())]
+    );
+}
+
+#[test]
+fn composed_reversible_prism_focuses_some_inside_ok() {
+    let ok_some = ok_prism::<Option<i32>, String>().compose_with_reversible_prism(some_prism());
+
+    assert_eq!(ok_some.try_get(&Ok(Some(3))), Ok(3));
+    assert_eq!(ok_some.try_get(&Ok(None)), Err(()));
+    assert_eq!(ok_some.try_get(&Err("boom".to_string())), Err(()));
+
+    assert_eq!(ok_some.reverse_get(&7), Ok(Some(7)));
+
+    let mut value = Ok(Some(1));
+    ok_some.set(&mut value, 2);
+    assert_eq!(value, Ok(Some(2)));
+
+    let mut failed = Err("boom".to_string());
+    ok_some.set(&mut failed, 2);
+    assert_eq!(failed, Err("boom".to_string()));
+}
+
+#[test]
+fn leading_int_prism_splits_numbers_off_strings() {
+    let leading = leading_int_prism();
+
+    for (input, expected) in [
+        ("-42rest", Ok((-42, "rest"))),
+        ("  17 apples", Ok((17, " apples"))),
+        ("007", Ok((7, ""))),
+        ("abc", Err(())),
+        ("-", Err(())),
+        ("+5", Err(())),
+        ("99999999999999999999", Err(())),
+    ] {
+        let expected = expected.map(|(v, rest): (i64, &str)| (v, rest.to_string()));
+        assert_eq!(leading.try_get(&input.to_string()), expected, "{input:?}");
+    }
+}
+
+#[test]
+fn leading_int_prism_prepends_rendered_integer() {
+    let leading = leading_int_prism();
+    let mut token = "12px".to_string();
+
+    assert_eq!(leading.reverse_get(&(-3, "em".to_string())), "-3em");
+
+    leading.set(&mut token, (24, "px".to_string()));
+    assert_eq!(token, "24px");
+}
+
+#[test]
+fn nonnull_prism_accepts_non_null_pointers() {
+    let mut value = 7;
+    let ptr: *mut i32 = &raw mut value;
+
+    let focus = nonnull_prism().try_get(&ptr).unwrap();
+    assert_eq!(focus.as_ptr(), ptr);
+    assert_eq!(nonnull_prism().reverse_get(&focus), ptr);
+}
+
+#[test]
+fn nonnull_prism_rejects_null_and_sets_non_null() {
+    let mut value = 7;
+    let mut ptr: *mut i32 = core::ptr::null_mut();
+
+    assert_eq!(nonnull_prism().try_get(&ptr), Err(()));
+
+    let target = core::ptr::NonNull::from(&mut value);
+    nonnull_prism().set(&mut ptr, target);
+    assert_eq!(ptr, target.as_ptr());
+    assert_eq!(nonnull_prism::<i32>(), nonnull_prism::<i32>());
+}
+
+#[test]
+fn substr_prism_splices_replacements_of_different_length() {
+    let name = substr_prism(6..11);
+    let mut text = "Hello world!".to_string();
+
+    assert_eq!(name.try_get(&text), Ok("world".to_string()));
+
+    name.set(&mut text, "Rust fans".to_string());
+    assert_eq!(text, "Hello Rust fans!");
+
+    substr_prism(6..6).set(&mut text, "dear ".to_string());
+    assert_eq!(text, "Hello dear Rust fans!");
+}
+
+#[test]
+fn substr_prism_rejects_invalid_ranges() {
+    let mut text = "héllo".to_string();
+
+    assert_eq!(
+        substr_prism(0..2).try_get(&text),
+        Err(SubstrError::NotCharBoundary { index: 2 })
+    );
+    assert_eq!(substr_prism(0..3).try_get(&text), Ok("hé".to_string()));
+    assert_eq!(
+        substr_prism(4..9).try_get(&text),
+        Err(SubstrError::OutOfBounds { len: 6 })
+    );
+
+    substr_prism(0..2).set(&mut text, "x".to_string());
+    assert_eq!(text, "héllo");
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Event {
+    Click { x: i32, y: i32, button: u8 },
+    Key(char),
+}
+
+fn match_click(event: &Event) -> Option<(i32, i32, u8)> {
+    match event {
+        Event::Click { x, y, button } => Some((*x, *y, *button)),
+        Event::Key(_) => None,
+    }
+}
+
+fn build_click((x, y, button): (i32, i32, u8)) -> Event {
+    Event::Click { x, y, button }
+}
+
+#[test]
+fn variant_prism_focuses_multi_field_variants_as_tuples() {
+    let click = variant_prism(match_click, build_click);
+    let source = Event::Click {
+        x: 3,
+        y: 4,
+        button: 1,
+    };
+
+    assert_eq!(click.try_get(&source), Ok((3, 4, 1)));
+    assert_eq!(click.try_get(&Event::Key('q')), Err(()));
+    assert_eq!(click.reverse_get(&(3, 4, 1)), source);
+}
+
+#[test]
+fn variant_prism_composes_with_tuple_lens() {
+    let button = variant_prism(match_click, build_click).compose_with_lens(tuple_lens::<2, _>());
+    let mut event = Event::Click {
+        x: 0,
+        y: 0,
+        button: 1,
+    };
+
+    button.set(&mut event, 2);
+    assert_eq!(
+        event,
+        Event::Click {
+            x: 0,
+            y: 0,
+            button: 2
+        }
+    );
+
+    let mut key = Event::Key('k');
+    button.set(&mut key, 3);
+    assert_eq!(key, Event::Key('k'));
+    assert_eq!(button.try_get(&key), Err(()));
+}
+
+#[test]
+fn can_set_reports_whether_the_focus_is_reachable() {
+    let click = variant_prism(match_click, build_click);
+    let button = variant_prism(match_click, build_click).compose_with_lens(tuple_lens::<2, _>());
+    let pressed = build_click((1, 2, 0));
+    let typed = Event::Key('q');
+
+    assert!(click.can_set(&pressed));
+    assert!(button.can_set(&pressed));
+    assert!(!click.can_set(&typed));
+    assert!(!button.can_set(&typed));
+
+    let x = mapped_lens(|p: &(i32, i32)| p.0, |p, x| p.0 = x);
+    assert!(x.can_set(&(0, 0)));
+    assert!(mapped_iso(|n: &u8| !n, |n| !n).can_set(&7));
+}
+
+// A container counting how many times it is accessed, to check how often a path visits it.
+#[derive(Clone)]
+struct Tracked<C> {
+    inner: C,
+    accesses: Cell<usize>,
+}
+
+impl<C> Tracked<C> {
+    fn new(inner: C) -> Self {
+        Tracked {
+            inner,
+            accesses: Cell::new(0),
+        }
+    }
+
+    fn touch(&self) -> &C {
+        self.accesses.set(self.accesses.get() + 1);
+        &self.inner
+    }
+
+    fn touch_mut(&mut self) -> &mut C {
+        *self.accesses.get_mut() += 1;
+        &mut self.inner
+    }
+}
+
+type Leaf = Tracked<Vec<u32>>;
+type Middle = Tracked<HashMap<&'static str, Leaf>>;
+type Root = Tracked<Vec<Middle>>;
+
+fn accesses(root: &Root) -> (usize, usize, usize) {
+    let middle = &root.inner[2];
+    (
+        root.accesses.get(),
+        middle.accesses.get(),
+        middle.inner["k"].accesses.get(),
+    )
+}
+
+#[test]
+fn ref_prism_chain_visits_each_container_once() {
+    let at_2 = field_prism(
+        |r: &Root| r.touch().get(2),
+        |r: &mut Root| r.touch_mut().get_mut(2),
+    );
+    let at_k = field_prism(
+        |m: &Middle| m.touch().get("k"),
+        |m: &mut Middle| m.touch_mut().get_mut("k"),
+    );
+    let at_0 = field_prism(
+        |l: &Leaf| l.touch().first(),
+        |l: &mut Leaf| l.touch_mut().first_mut(),
+    );
+    let path = at_2
+        .compose_with_ref_prism::<(), _, _>(at_k)
+        .compose_with_ref_prism::<(), _, _>(at_0);
+
+    let middle = || Tracked::new(HashMap::from([("k", Tracked::new(vec![10, 20]))]));
+    let mut root = Tracked::new(vec![middle(), middle(), middle()]);
+
+    *path.try_get_mut(&mut root).unwrap() += 1;
+    assert_eq!(root.inner[2].inner["k"].inner, vec![11, 20]);
+    assert_eq!(accesses(&root), (1, 1, 1));
+
+    path.set(&mut root, 30);
+    assert_eq!(accesses(&root), (2, 2, 2));
+
+    assert_eq!(path.try_get(&root), Ok(30));
+    assert_eq!(accesses(&root), (3, 3, 3));
+    assert_eq!(root.inner[1].inner["k"].inner, vec![10, 20]);
+
+    let mut short = Tracked::new(vec![middle()]);
+    assert_eq!(path.try_get_mut(&mut short), Err(()));
+    path.set(&mut short, 1);
+    assert_eq!(short.inner[0].inner["k"].inner, vec![10, 20]);
+}
@@ -0,0 +1,113 @@
+use crate::test::helpers::{Record, records};
+use crate::{
+    HasOver, HasSetter, map_merge, map_merge_with, mapped_lens, mapped_setter, sorted_vec_insert,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::collections::HashMap;
+
+#[test]
+fn set_all_broadcasts_value_across_sources() {
+    let mut records = records();
+    let active_setter = mapped_setter(|r: &mut Record, v| r.active = v);
+
+    active_setter.set_all(&mut records, true);
+
+    assert!(records.iter().all(|r| r.active));
+    assert_eq!(
+        records.iter().map(|r| r.name).collect::<Vec<_>>(),
+        vec!["a", "b", "c", "d"]
+    );
+}
+
+#[test]
+fn modify_all_sources_transforms_each_focus() {
+    let mut records = records();
+    let score_lens = mapped_lens(|r: &Record| r.score, |r, v| r.score = v);
+
+    score_lens.modify_all_sources(&mut records, |score| score * 10);
+
+    assert_eq!(
+        records.iter().map(|r| r.score).collect::<Vec<_>>(),
+        vec![30, 70, 10, 70]
+    );
+}
+
+#[test]
+fn map_merge_overwrites_overlapping_and_inserts_disjoint_keys() {
+    let mut base = HashMap::from([("a", 1), ("b", 2)]);
+
+    map_merge().set(&mut base, HashMap::from([("b", 20), ("c", 30)]));
+
+    assert_eq!(base, HashMap::from([("a", 1), ("b", 20), ("c", 30)]));
+}
+
+#[test]
+fn map_merge_with_combines_colliding_values() {
+    let mut base = HashMap::from([("a", vec![1]), ("b", vec![2])]);
+    let append = map_merge_with(|existing: &mut Vec<u32>, incoming| existing.extend(incoming));
+
+    append.set(&mut base, HashMap::from([("b", vec![3]), ("c", vec![4])]));
+
+    assert_eq!(
+        base,
+        HashMap::from([("a", vec![1]), ("b", vec![2, 3]), ("c", vec![4])])
+    );
+}
+
+#[test]
+fn sorted_vec_insert_keeps_vec_sorted() {
+    let insert = sorted_vec_insert();
+    let mut values = vec![];
+
+    insert.set(&mut values, 5);
+    assert_eq!(values, vec![5]);
+    insert.set(&mut values, 1);
+    assert_eq!(values, vec![1, 5]);
+    insert.set(&mut values, 3);
+    assert_eq!(values, vec![1, 3, 5]);
+    insert.set(&mut values, 9);
+    assert_eq!(values, vec![1, 3, 5, 9]);
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Ranked {
+    rank: u32,
+    name: &'static str,
+}
+
+impl PartialEq for Ranked {
+    fn eq(&self, other: &Self) -> bool {
+        self.rank == other.rank
+    }
+}
+
+impl Eq for Ranked {}
+
+impl PartialOrd for Ranked {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Ranked {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.rank.cmp(&other.rank)
+    }
+}
+
+#[test]
+fn sorted_vec_insert_places_duplicates_after_equal_values() {
+    let ranked = |rank, name| Ranked { rank, name };
+    let mut values = vec![
+        ranked(1, "a"),
+        ranked(2, "b"),
+        ranked(2, "c"),
+        ranked(3, "d"),
+    ];
+
+    sorted_vec_insert().set(&mut values, ranked(2, "new"));
+
+    let names: Vec<_> = values.iter().map(|r| r.name).collect();
+    assert_eq!(names, vec!["a", "b", "c", "new", "d"]);
+}
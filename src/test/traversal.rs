@@ -0,0 +1,349 @@
+use crate::test::helpers::{Record, records};
+use crate::{
+    HasFilterMapAll, HasFold, HasModifyAll, array_each, each, each_removable, flatten_traversal,
+    mapped_lens, mapped_traversal, option_each, prism_opt,
+};
+use alloc::vec;
+use alloc::vec::Vec;
+use std::collections::HashMap;
+
+#[test]
+fn each_modifies_every_element_in_order() {
+    let mut values = vec![1, 2, 3];
+    let mut visited = vec![];
+
+    each().modify_all(&mut values, |x| {
+        visited.push(x);
+        x * 2
+    });
+
+    assert_eq!(visited, vec![1, 2, 3]);
+    assert_eq!(each().get_all(&values), vec![2, 4, 6]);
+}
+
+#[test]
+fn each_removable_keeps_even_values_while_doubling_them() {
+    let mut values = vec![1, 2, 3, 4, 5, 6];
+
+    each_removable().filter_map_all(&mut values, |x| (x % 2 == 0).then_some(x * 2));
+
+    assert_eq!(values, vec![4, 8, 12]);
+}
+
+#[test]
+fn each_removable_modify_all_keeps_every_element() {
+    let mut values = vec![3, 1, 2];
+
+    each_removable().modify_all(&mut values, |x| x + 1);
+
+    assert_eq!(values, vec![4, 2, 3]);
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Inventory {
+    items: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct Store {
+    inventory: Inventory,
+}
+
+#[test]
+fn lens_composed_with_traversal_modifies_nested_elements() {
+    let mut store = Store {
+        inventory: Inventory {
+            items: vec![1, 2, 3],
+        },
+    };
+
+    let inventory_lens = mapped_lens(|s: &Store| s.inventory.clone(), |s, v| s.inventory = v);
+    let items_lens = mapped_lens(|i: &Inventory| i.items.clone(), |i, v| i.items = v);
+    let all_items = inventory_lens
+        .compose_with_lens(items_lens)
+        .compose_with_traversal(each());
+
+    all_items.modify_all(&mut store, |x| x * 10);
+
+    assert_eq!(store.inventory.items, vec![10, 20, 30]);
+    assert_eq!(all_items.get_all(&store), vec![10, 20, 30]);
+}
+
+#[test]
+fn traversal_composed_with_traversal_visits_every_inner_element() {
+    let mut grid = vec![vec![1, 2], vec![], vec![3]];
+    let cells = each().compose_with_traversal(each());
+
+    assert_eq!(cells.get_all(&grid), vec![1, 2, 3]);
+
+    cells.modify_all(&mut grid, |x| x + 1);
+    assert_eq!(grid, vec![vec![2, 3], vec![], vec![4]]);
+}
+
+#[test]
+fn prism_composed_with_traversal_skips_missing_focus() {
+    let some = prism_opt(Option::clone, Some);
+    let values = some.compose_with_traversal(each());
+
+    let mut present = Some(vec![1, 2]);
+    let mut missing: Option<Vec<i32>> = None;
+
+    values.modify_all(&mut present, |x| -x);
+    values.modify_all(&mut missing, |x| -x);
+
+    assert_eq!(present, Some(vec![-1, -2]));
+    assert_eq!(missing, None);
+    assert_eq!(values.get_all(&missing), vec![]);
+}
+
+#[test]
+fn max_and_min_of_numeric_field_across_records() {
+    let scores = mapped_traversal(
+        |rs: &Vec<Record>| rs.iter().map(|r| r.score).collect::<Vec<_>>(),
+        |rs, f| rs.iter_mut().for_each(|r| r.score = f(r.score)),
+    );
+
+    assert_eq!(scores.max(&records()), Some(7));
+    assert_eq!(scores.min(&records()), Some(1));
+    assert_eq!(scores.max(&vec![]), None);
+    assert_eq!(scores.min(&vec![]), None);
+}
+
+#[test]
+fn sum_and_product_of_numeric_focuses() {
+    let scores = mapped_traversal(
+        |rs: &Vec<Record>| rs.iter().map(|r| r.score).collect::<Vec<_>>(),
+        |rs, f| rs.iter_mut().for_each(|r| r.score = f(r.score)),
+    );
+
+    assert_eq!(scores.sum(&records()), 3 + 7 + 1 + 7);
+    assert_eq!(scores.sum(&vec![]), 0);
+
+    assert_eq!(array_each().product(&[2, 3, 4]), 24);
+    assert_eq!(array_each::<u8, 0>().product(&[]), 1);
+}
+
+#[test]
+fn join_concatenates_names_with_separator() {
+    let names = mapped_traversal(
+        |rs: &Vec<Record>| rs.iter().map(|r| r.name).collect::<Vec<_>>(),
+        |rs, f| rs.iter_mut().for_each(|r| r.name = f(r.name)),
+    );
+
+    assert_eq!(names.join(&records(), ", "), "a, b, c, d");
+    assert_eq!(names.join(&records()[..1].to_vec(), ", "), "a");
+    assert_eq!(names.join(&vec![], ", "), "");
+}
+
+#[test]
+fn min_and_max_by_key_follow_iterator_tie_breaking() {
+    let all = records();
+
+    assert_eq!(
+        each()
+            .max_by_key(&all, |r: &Record| r.score)
+            .map(|r| r.name),
+        Some("d")
+    );
+    assert_eq!(
+        each()
+            .min_by_key(&all, |r: &Record| r.score)
+            .map(|r| r.name),
+        Some("c")
+    );
+    assert_eq!(
+        each()
+            .min_by_key(&all, |r: &Record| r.score / 4)
+            .map(|r| r.name),
+        Some("a")
+    );
+    assert_eq!(each().max_by_key(&Vec::<Record>::new(), |r| r.score), None);
+}
+
+#[test]
+fn count_visits_every_focus() {
+    assert_eq!(each().count(&vec![1, 2, 3]), 3);
+    assert_eq!(each().count(&Vec::<i32>::new()), 0);
+}
+
+#[test]
+fn any_and_all_short_circuit() {
+    let values = vec![1, 2, 3, 4];
+
+    let mut visited = vec![];
+    assert!(each().any(&values, |x| {
+        visited.push(x);
+        x == 2
+    }));
+    assert_eq!(visited, vec![1, 2]);
+
+    let mut visited = vec![];
+    assert!(!each().all(&values, |x| {
+        visited.push(x);
+        x < 3
+    }));
+    assert_eq!(visited, vec![1, 2, 3]);
+
+    assert!(each().all(&values, |x| x > 0));
+    assert!(!each().any(&values, |x| x > 4));
+    assert!(each().all(&Vec::<i32>::new(), |_| false));
+    assert!(!each().any(&Vec::<i32>::new(), |_| true));
+}
+
+#[test]
+fn find_returns_first_match_and_stops() {
+    let values = vec![5, 8, 11, 14];
+
+    let mut visited = vec![];
+    assert_eq!(
+        each().find(&values, |x| {
+            visited.push(*x);
+            x % 2 == 0
+        }),
+        Some(8)
+    );
+    assert_eq!(visited, vec![5, 8]);
+
+    assert_eq!(each().find(&values, |x| *x > 20), None);
+}
+
+#[test]
+fn enumerate_pairs_focus_values_with_positions() {
+    let values = vec!['x', 'y', 'z'];
+
+    assert_eq!(
+        each().enumerate().get_all(&values),
+        vec![(0, 'x'), (1, 'y'), (2, 'z')]
+    );
+    assert_eq!(each().enumerate().get_all(&Vec::<char>::new()), vec![]);
+}
+
+#[test]
+fn enumerate_counts_positions_after_filtering() {
+    let even = mapped_traversal(
+        |x: &u32| (x & 1 == 0).then_some(*x),
+        |x, f| {
+            if *x & 1 == 0 {
+                *x = f(*x);
+            }
+        },
+    );
+    let values = vec![1, 2, 3, 4, 6];
+    let evens = each().compose_with_traversal(even);
+
+    assert_eq!(evens.get_all(&values), vec![2, 4, 6]);
+    assert_eq!(
+        evens.enumerate().get_all(&values),
+        vec![(0, 2), (1, 4), (2, 6)]
+    );
+}
+
+#[test]
+fn enumerate_stops_with_the_underlying_fold() {
+    let values = vec![5, 6, 7, 8];
+
+    assert_eq!(
+        each().enumerate().find(&values, |(_, x)| x % 2 == 0),
+        Some((1, 6))
+    );
+    assert_eq!(each().enumerate().count(&values), 4);
+}
+
+#[test]
+fn flatten_traversal_doubles_jagged_rows_preserving_structure() {
+    let mut rows = vec![vec![1, 2, 3], vec![], vec![4], vec![5, 6]];
+    let mut visited = vec![];
+
+    flatten_traversal().modify_all(&mut rows, |x| {
+        visited.push(x);
+        x * 2
+    });
+
+    assert_eq!(visited, [1, 2, 3, 4, 5, 6]);
+    assert_eq!(rows, vec![vec![2, 4, 6], vec![], vec![8], vec![10, 12]]);
+    assert_eq!(flatten_traversal().get_all(&rows), [2, 4, 6, 8, 10, 12]);
+}
+
+#[test]
+fn array_each_composes_with_field_lens() {
+    struct Packet {
+        id: u8,
+        payload: [u8; 16],
+    }
+
+    let mut packet = Packet {
+        id: 7,
+        payload: [1; 16],
+    };
+    let payload = mapped_lens(|p: &Packet| p.payload, |p, v| p.payload = v);
+    let bytes = payload.compose_with_traversal(array_each());
+
+    bytes.modify_all(&mut packet, |b| b + 1);
+
+    assert_eq!(packet.payload, [2; 16]);
+    assert_eq!(packet.id, 7);
+    assert_eq!(bytes.fold(&packet, 0, |sum, b| sum + u32::from(b)), 32);
+}
+
+#[test]
+fn group_by_buckets_records_by_category() {
+    #[derive(Debug, Clone, PartialEq)]
+    struct Expense {
+        category: &'static str,
+        amount: u32,
+    }
+
+    let expenses = vec![
+        Expense {
+            category: "food",
+            amount: 12,
+        },
+        Expense {
+            category: "rent",
+            amount: 900,
+        },
+        Expense {
+            category: "food",
+            amount: 30,
+        },
+    ];
+
+    let groups = each().group_by(&expenses, |e: &Expense| e.category);
+
+    assert_eq!(groups.len(), 2);
+    assert_eq!(groups["food"], [expenses[0].clone(), expenses[2].clone()]);
+    assert_eq!(groups["rent"], [expenses[1].clone()]);
+
+    let totals: HashMap<_, u32> = groups
+        .iter()
+        .map(|(category, bucket)| (*category, bucket.iter().map(|e| e.amount).sum()))
+        .collect();
+    assert_eq!(totals, HashMap::from([("food", 42), ("rent", 900)]));
+}
+
+#[test]
+fn option_each_folds_zero_or_one_values() {
+    assert_eq!(option_each::<i32>().get_all(&None), Vec::<i32>::new());
+    assert_eq!(option_each().get_all(&Some(4)), vec![4]);
+    assert_eq!(option_each().fold(&Some(4), 10, |sum, x| sum + x), 14);
+}
+
+#[test]
+fn option_each_only_modifies_some() {
+    let mut present = Some(3);
+    let mut absent: Option<i32> = None;
+    let mut calls = 0;
+
+    option_each().modify_all(&mut present, |x| {
+        calls += 1;
+        x * 2
+    });
+    option_each().modify_all(&mut absent, |x| {
+        calls += 1;
+        x * 2
+    });
+
+    assert_eq!(present, Some(6));
+    assert_eq!(absent, None);
+    assert_eq!(calls, 1);
+}
@@ -0,0 +1,150 @@
+use core::convert::Infallible;
+use core::fmt;
+
+/// The error type of optics composed with [`try_optic!`], recording which stage failed.
+///
+/// Each variant wraps the error of the optic at the corresponding position in the chain, so a
+/// failure at any stage stays distinguishable. Stages the chain does not have use `Infallible`,
+/// and can never occur.
+///
+/// [`try_optic!`]: crate::try_optic
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CompositeError<E1, E2, E3 = Infallible, E4 = Infallible, E5 = Infallible> {
+    /// The first optic of the chain failed.
+    Stage1(E1),
+    /// The second optic of the chain failed.
+    Stage2(E2),
+    /// The third optic of the chain failed.
+    Stage3(E3),
+    /// The fourth optic of the chain failed.
+    Stage4(E4),
+    /// The fifth optic of the chain failed.
+    Stage5(E5),
+}
+
+impl<E1, E2, E3, E4, E5> fmt::Display for CompositeError<E1, E2, E3, E4, E5>
+where
+    E1: fmt::Display,
+    E2: fmt::Display,
+    E3: fmt::Display,
+    E4: fmt::Display,
+    E5: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CompositeError::Stage1(e) => write!(f, "stage 1 failed: {e}"),
+            CompositeError::Stage2(e) => write!(f, "stage 2 failed: {e}"),
+            CompositeError::Stage3(e) => write!(f, "stage 3 failed: {e}"),
+            CompositeError::Stage4(e) => write!(f, "stage 4 failed: {e}"),
+            CompositeError::Stage5(e) => write!(f, "stage 5 failed: {e}"),
+        }
+    }
+}
+
+impl<E1, E2, E3, E4, E5> core::error::Error for CompositeError<E1, E2, E3, E4, E5>
+where
+    E1: fmt::Debug + fmt::Display,
+    E2: fmt::Debug + fmt::Display,
+    E3: fmt::Debug + fmt::Display,
+    E4: fmt::Debug + fmt::Display,
+    E5: fmt::Debug + fmt::Display,
+{
+}
+
+/// Composes a chain of two to five prism-like optics into a single `Prism`, merging their errors
+/// into a [`CompositeError`].
+///
+/// Any optic implementing [`Prism`] can be part of the chain, which includes lenses, isos and
+/// fallible isos. The composed optic focuses through the optics from left to right, and when one
+/// of them fails, the error is wrapped in the `CompositeError` variant of its stage, e.g. a
+/// failure of the second optic becomes `CompositeError::Stage2`.
+///
+/// This saves spelling out the error mappers of nested `compose_with_*_with_mappers` calls.
+///
+/// # Example
+///
+/// ```rust
+/// use optics::{CompositeError, HasGetter, mapped_lens, prism_opt, try_optic};
+///
+/// struct Config { port: Option<String> }
+///
+/// let port = mapped_lens(|c: &Config| c.port.clone(), |c, p| c.port = p);
+/// let some = prism_opt(|o: &Option<String>| o.clone(), Some);
+/// let parsed = optics::mapped_prism(|s: &String| s.parse::<u16>(), |s, v| *s = v.to_string());
+///
+/// let port_number = try_optic!(port, some, parsed);
+///
+/// assert_eq!(port_number.try_get(&Config { port: Some("80".into()) }), Ok(80));
+/// assert_eq!(port_number.try_get(&Config { port: None }), Err(CompositeError::Stage2(())));
+/// assert!(matches!(
+///     port_number.try_get(&Config { port: Some("x".into()) }),
+///     Err(CompositeError::Stage3(_))
+/// ));
+/// ```
+///
+/// [`Prism`]: crate::Prism
+#[macro_export]
+macro_rules! try_optic {
+    ($o1:expr, $o2:expr $(,)?) => {
+        $crate::composed_prism(
+            $o1,
+            $o2,
+            $crate::CompositeError::<_, _>::Stage1,
+            $crate::CompositeError::<_, _>::Stage2,
+        )
+    };
+    ($o1:expr, $o2:expr, $o3:expr $(,)?) => {
+        $crate::composed_prism(
+            $crate::composed_prism(
+                $o1,
+                $o2,
+                $crate::CompositeError::<_, _, _>::Stage1,
+                $crate::CompositeError::<_, _, _>::Stage2,
+            ),
+            $o3,
+            ::core::convert::identity,
+            $crate::CompositeError::<_, _, _>::Stage3,
+        )
+    };
+    ($o1:expr, $o2:expr, $o3:expr, $o4:expr $(,)?) => {
+        $crate::composed_prism(
+            $crate::composed_prism(
+                $crate::composed_prism(
+                    $o1,
+                    $o2,
+                    $crate::CompositeError::<_, _, _, _>::Stage1,
+                    $crate::CompositeError::<_, _, _, _>::Stage2,
+                ),
+                $o3,
+                ::core::convert::identity,
+                $crate::CompositeError::<_, _, _, _>::Stage3,
+            ),
+            $o4,
+            ::core::convert::identity,
+            $crate::CompositeError::<_, _, _, _>::Stage4,
+        )
+    };
+    ($o1:expr, $o2:expr, $o3:expr, $o4:expr, $o5:expr $(,)?) => {
+        $crate::composed_prism(
+            $crate::composed_prism(
+                $crate::composed_prism(
+                    $crate::composed_prism(
+                        $o1,
+                        $o2,
+                        $crate::CompositeError::<_, _, _, _, _>::Stage1,
+                        $crate::CompositeError::<_, _, _, _, _>::Stage2,
+                    ),
+                    $o3,
+                    ::core::convert::identity,
+                    $crate::CompositeError::<_, _, _, _, _>::Stage3,
+                ),
+                $o4,
+                ::core::convert::identity,
+                $crate::CompositeError::<_, _, _, _, _>::Stage4,
+            ),
+            $o5,
+            ::core::convert::identity,
+            $crate::CompositeError::<_, _, _, _, _>::Stage5,
+        )
+    };
+}
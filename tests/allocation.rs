@@ -0,0 +1,63 @@
+//! Checks that traversals modify their focus values in place, without allocating.
+//!
+//! This lives in its own integration test binary, as it installs a counting global allocator,
+//! which the library crate itself cannot do under `#![forbid(unsafe_code)]`.
+
+use optics::{HasModifyAll, array_each, each};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+struct CountingAllocator;
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static COUNTING: Cell<bool> = const { Cell::new(false) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if COUNTING.with(Cell::get) {
+            ALLOCATIONS.fetch_add(1, Ordering::SeqCst);
+        }
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.load(Ordering::SeqCst);
+    COUNTING.with(|c| c.set(true));
+    f();
+    COUNTING.with(|c| c.set(false));
+    ALLOCATIONS.load(Ordering::SeqCst) - before
+}
+
+#[test]
+fn each_modify_all_does_not_allocate() {
+    let mut values: Vec<u64> = (0..100_000).collect();
+    let traversal = each();
+
+    let allocations = count_allocations(|| traversal.modify_all(&mut values, |x| x * 2));
+
+    assert_eq!(allocations, 0);
+    assert_eq!(values[99_999], 199_998);
+}
+
+#[test]
+fn array_each_modify_all_does_not_allocate() {
+    let mut bytes = [0u8; 16];
+    let traversal = array_each();
+
+    let allocations = count_allocations(|| traversal.modify_all(&mut bytes, |b| b + 3));
+
+    assert_eq!(allocations, 0);
+    assert_eq!(bytes, [3; 16]);
+}